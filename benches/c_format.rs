@@ -0,0 +1,70 @@
+//! Compares `c_format`, which parses format string on every call, with compiled `CFormat`.
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+use rustrings::format::CFormat;
+use rustrings::format::CFormatArgument;
+use rustrings::format::c_format;
+
+const ITERATIONS: u32 = 200_000;
+
+/// Number of calls, whose inputs are prepared before timing them.
+const BATCH: u32 = 1_000;
+
+/// Measures average time of one call. Inputs are built by `input` outside of timed part,
+/// so only `function` is measured.
+fn measure<I, F: FnMut(I) -> String>(
+	input: impl Fn() -> I,
+	mut function: F,
+) -> Duration {
+	/* warm up */
+	for _ in 0..ITERATIONS / 10 {
+		black_box(function(input()));
+	}
+	let mut elapsed = Duration::ZERO;
+	for _ in 0..ITERATIONS / BATCH {
+		let inputs: Vec<I> = (0..BATCH).map(|_| input()).collect();
+		let start = Instant::now();
+		for input in inputs {
+			black_box(function(black_box(input)));
+		}
+		elapsed += start.elapsed();
+	}
+	elapsed / ITERATIONS
+}
+
+fn main() {
+	let formats = [
+		"%s",
+		"[%-8s] %s:%d: %s",
+		"%5.1f%% of %'llu bytes used by %s (pid %d)",
+	];
+	let arguments = vec![
+		vec![
+			CFormatArgument::String("hello".to_string()),
+		],
+		vec![
+			CFormatArgument::String("warn".to_string()),
+			CFormatArgument::String("src/main.rs".to_string()),
+			CFormatArgument::Int32(42),
+			CFormatArgument::String("disk is almost full".to_string()),
+		],
+		vec![
+			CFormatArgument::Float64(93.75),
+			CFormatArgument::UInt64(512_000_000_000),
+			CFormatArgument::String("postgres".to_string()),
+			CFormatArgument::Int32(1337),
+		],
+	];
+	for (format, arguments) in formats.iter().zip(arguments) {
+		let compiled = CFormat::new(format).unwrap();
+		assert_eq!(compiled.format(&arguments), c_format(format.to_string(), arguments.clone()));
+		/* c_format takes owned format string and arguments, which are cloned outside of timed part */
+		let parsed = measure(|| (format.to_string(), arguments.clone()), |(format, arguments)| c_format(format, arguments));
+		let reused = measure(|| &arguments, |arguments| compiled.format(arguments));
+		println!("{:<45} c_format: {:>8?}  CFormat::format: {:>8?}", format!("{:?}", format), parsed, reused);
+	}
+}
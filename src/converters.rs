@@ -2,6 +2,10 @@
 //! 
 //! This module contains converters to convert numbers to strings and strings to numbers.
 
+use crate::format::FormatDirection;
+use crate::format::FormatOptions;
+use crate::format::format_text;
+
 /// Number base representation.
 /// 
 /// # Examples
@@ -17,7 +21,7 @@
 /// // assert_ne!(itoa_signed(12345, Base::new(1), Case::Lower), "?"); // panics, base is less than 2
 /// // assert_ne!(itoa_signed(12345, Base::new(37), Case::Lower), "?"); // panics, base is greater than 36
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Base(i32);
 
 impl Base {
@@ -50,10 +54,23 @@ impl Base {
 	pub fn unsafe_new(base: i32) -> Base {
 		Base(base)
 	}
+	
+	/// Returns base as number.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::converters::Base;
+	/// 
+	/// assert_eq!(Base::new(16).get_value(), 16);
+	/// ```
+	pub fn get_value(&self) -> i32 {
+		self.0
+	}
 }
 
 /// ASCII case representation.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Case {
 	/// Represents lower-case letters.
 	Lower,
@@ -167,6 +184,83 @@ pub fn itoa_unsigned(number: usize, base: Base, case: Case) -> String {
 		.collect()
 }
 
+/// Converts floating point numbers into Strings with `precision` digits after decimal point.
+/// Rounds half to even, like C `printf`. Infinities and NaN are written as `inf`, `-inf` and `nan`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::ftoa;
+/// 
+/// assert_eq!(ftoa(3.14159, 2), "3.14");
+/// assert_eq!(ftoa(-0.5, 3), "-0.500");
+/// assert_eq!(ftoa(2.5, 0), "2");
+/// assert_eq!(ftoa(f64::INFINITY, 2), "inf");
+/// assert_eq!(ftoa(f64::NAN, 2), "nan");
+/// ```
+pub fn ftoa(
+	number: f64,
+	precision: usize,
+) -> String {
+	if number.is_nan() {
+		return "nan".to_string();
+	}
+	if number.is_infinite() {
+		return if number < 0.0 {
+			"-inf".to_string()
+		} else {
+			"inf".to_string()
+		};
+	}
+	format!("{:.*}", precision, number)
+}
+
+/// Converts floating point numbers into Strings in scientific notation with `precision` digits after decimal point.
+/// Exponent is written like in C `printf`: with sign and at least two digits. Case affects `e` and non-finite values.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Case, etoa};
+/// 
+/// assert_eq!(etoa(0.000047, 1, Case::Lower), "4.7e-05");
+/// assert_eq!(etoa(123456.0, 2, Case::Upper), "1.23E+05");
+/// assert_eq!(etoa(0.0, 0, Case::Lower), "0e+00");
+/// assert_eq!(etoa(f64::NEG_INFINITY, 2, Case::Upper), "-INF");
+/// ```
+pub fn etoa(
+	number: f64,
+	precision: usize,
+	case: Case,
+) -> String {
+	if !number.is_finite() {
+		let result = ftoa(number, precision);
+		return match case {
+			Case::Lower => result,
+			Case::Upper => result.to_ascii_uppercase(),
+		};
+	}
+	let result = format!("{:.*e}", precision, number);
+	let (mantissa, exponent) = result
+		.split_once('e')
+		.unwrap();
+	let (sign, digits) = match exponent.strip_prefix('-') {
+		Some(digits) => ('-', digits),
+		None => ('+', exponent),
+	};
+	let mut result = mantissa.to_string();
+	result.push(match case {
+		Case::Lower => 'e',
+		Case::Upper => 'E',
+	});
+	result.push(sign);
+	if digits.len() < 2 {
+		result.push('0');
+	}
+	result.push_str(digits);
+	result
+}
+
 /// Returned by `atoi_signed` and `atoi_unsigned` if parsing of string failed.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParseIntKind {
@@ -201,7 +295,7 @@ pub enum ParseIntKind {
 /// assert_eq!(atoi_signed(&"-ff".to_string(), Base::new(16), false), Ok(-255));
 /// ```
 pub fn atoi_signed(
-	o: &String,
+	o: &str,
 	base: Base,
 	ignore_overflow: bool,
 ) -> Result<isize, ParseIntKind> {
@@ -230,7 +324,6 @@ pub fn atoi_signed(
 			char == '\n' ||
 			char == '\x0b' ||
 			char == '\x0c' ||
-			char == '\n' ||
 			char == ' ' ||
 			char == '_'
 		{
@@ -293,7 +386,7 @@ pub fn atoi_signed(
 /// assert_eq!(atoi_unsigned(&"ffffffffffffffff".to_string(), Base::new(16), false), Ok(18446744073709551615));
 /// ```
 pub fn atoi_unsigned(
-	o: &String,
+	o: &str,
 	base: Base,
 	ignore_overflow: bool,
 ) -> Result<usize, ParseIntKind> {
@@ -317,7 +410,6 @@ pub fn atoi_unsigned(
 			char == '\n' ||
 			char == '\x0b' ||
 			char == '\x0c' ||
-			char == '\n' ||
 			char == ' ' ||
 			char == '_'
 		{
@@ -336,7 +428,7 @@ pub fn atoi_unsigned(
 			return Err(ParseIntKind::Overflow);
 		}
 		result = tmp.0;
-		tmp = result.overflowing_add(position.unwrap() as usize);
+		tmp = result.overflowing_add(position.unwrap());
 		if !ignore_overflow && tmp.1 {
 			return Err(ParseIntKind::Overflow);
 		}
@@ -353,6 +445,1401 @@ pub fn atoi_unsigned(
 	}
 }
 
+/// Roman numeral notation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RomanNotation {
+	/// Subtractive notation, e.g. `IV`, `IX`, `XL`, `XC`, `CD` and `CM`.
+	Standard,
+	
+	/// Additive notation, e.g. `IIII`, `VIIII`, `XXXX`, `LXXXX`, `CCCC` and `DCCCC`.
+	Additive,
+}
+
+/// Roman numeral formatting options.
+#[derive(Debug, Clone)]
+pub struct RomanOptions {
+	/// Notation of numeral.
+	pub notation: RomanNotation,
+	
+	/// Use Unicode number forms (`Ⅰ`, `Ⅴ`, `Ⅻ`, ...) instead of ASCII letters.
+	pub unicode: bool,
+	
+	/// Allow values greater than 3999, writing thousands with overline (vinculum).
+	pub overline: bool,
+}
+
+impl RomanOptions {
+	/// Constructs RomanOptions.
+	pub fn new(
+		notation: RomanNotation,
+		unicode: bool,
+		overline: bool,
+	) -> RomanOptions {
+		RomanOptions {
+			notation,
+			unicode,
+			overline,
+		}
+	}
+}
+
+impl Default for RomanOptions {
+	fn default() -> RomanOptions {
+		RomanOptions::new(RomanNotation::Standard, false, false)
+	}
+}
+
+/// Returned by `atoi_roman` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseRomanKind {
+	/// Empty string.
+	Empty,
+	
+	/// Character which is not Roman numeral.
+	InvalidCharacter,
+	
+	/// Numeral is well-formed, but not written in canonical form (e.g. `IIII`, `IM`, `MCm`).
+	NonCanonical,
+}
+
+const ROMAN_STANDARD: [(usize, &str); 13] = [
+	(1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+	(100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+	(10, "X"), (9, "IX"), (5, "V"), (4, "IV"),
+	(1, "I"),
+];
+
+const ROMAN_ADDITIVE: [(usize, &str); 7] = [
+	(1000, "M"), (500, "D"), (100, "C"), (50, "L"),
+	(10, "X"), (5, "V"), (1, "I"),
+];
+
+const COMBINING_OVERLINE: char = '\u{0305}';
+
+fn roman_letters(
+	number: usize,
+	notation: RomanNotation,
+) -> String {
+	let table: &[(usize, &str)] = match notation {
+		RomanNotation::Standard => &ROMAN_STANDARD,
+		RomanNotation::Additive => &ROMAN_ADDITIVE,
+	};
+	let mut number = number;
+	let mut result = String::new();
+	for (value, letters) in table {
+		while number >= *value {
+			result.push_str(letters);
+			number -= value;
+		}
+	}
+	result
+}
+
+fn roman_letter_value(letter: char) -> usize {
+	match letter {
+		'I' => 1,
+		'V' => 5,
+		'X' => 10,
+		'L' => 50,
+		'C' => 100,
+		'D' => 500,
+		'M' => 1000,
+		_ => 0,
+	}
+}
+
+fn roman_glyph(
+	letter: char,
+	unicode: bool,
+	case: &Case,
+) -> char {
+	let letter = match case {
+		Case::Lower => letter.to_ascii_lowercase(),
+		Case::Upper => letter,
+	};
+	if !unicode {
+		return letter;
+	}
+	let offset = match letter.to_ascii_uppercase() {
+		'I' => 0x0,
+		'V' => 0x4,
+		'X' => 0x9,
+		'L' => 0xC,
+		'C' => 0xD,
+		'D' => 0xE,
+		_ => 0xF,
+	};
+	let start = if letter.is_ascii_lowercase() {
+		0x2170
+	} else {
+		0x2160
+	};
+	char::from_u32(start + offset).unwrap()
+}
+
+/// Converts unsigned integers into Roman numerals, returning `None` if number can't be written.
+/// Without overline, range is `1..=3999`; with overline, range is `1..=3999999`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Case, RomanNotation, RomanOptions, itoa_roman};
+/// 
+/// assert_eq!(itoa_roman(1994, RomanOptions::default(), Case::Upper), Some("MCMXCIV".to_string()));
+/// assert_eq!(itoa_roman(14, RomanOptions::default(), Case::Lower), Some("xiv".to_string()));
+/// assert_eq!(itoa_roman(14, RomanOptions::new(RomanNotation::Additive, false, false), Case::Upper), Some("XIIII".to_string()));
+/// assert_eq!(itoa_roman(12, RomanOptions::new(RomanNotation::Standard, true, false), Case::Upper), Some("Ⅻ".to_string()));
+/// assert_eq!(itoa_roman(49, RomanOptions::new(RomanNotation::Standard, true, false), Case::Lower), Some("ⅹⅼⅰⅹ".to_string()));
+/// assert_eq!(itoa_roman(5001, RomanOptions::new(RomanNotation::Standard, false, true), Case::Upper), Some("V\u{305}I".to_string()));
+/// assert_eq!(itoa_roman(0, RomanOptions::default(), Case::Upper), None);
+/// assert_eq!(itoa_roman(4000, RomanOptions::default(), Case::Upper), None);
+/// ```
+pub fn itoa_roman(
+	number: usize,
+	options: RomanOptions,
+	case: Case,
+) -> Option<String> {
+	let limit = if options.overline {
+		3_999_999
+	} else {
+		3999
+	};
+	if number == 0 || number > limit {
+		return None;
+	}
+	if options.unicode && options.notation == RomanNotation::Standard && number <= 12 {
+		let start = match case {
+			Case::Lower => 0x2170,
+			Case::Upper => 0x2160,
+		};
+		return char::from_u32(start + number as u32 - 1).map(String::from);
+	}
+	let (thousands, rest) = if number >= 4000 {
+		(number / 1000, number % 1000)
+	} else {
+		(0, number)
+	};
+	let mut result = String::new();
+	for letter in roman_letters(thousands, options.notation).chars() {
+		result.push(roman_glyph(letter, options.unicode, &case));
+		result.push(COMBINING_OVERLINE);
+	}
+	for letter in roman_letters(rest, options.notation).chars() {
+		result.push(roman_glyph(letter, options.unicode, &case));
+	}
+	Some(result)
+}
+
+/// Converts Roman numeral to number, returning `Ok(usize)` if successfully parsed, otherwise returns `Err(ParseRomanKind)` on error.
+/// Accepts ASCII letters, Unicode number forms and overlined thousands, but only in canonical (standard) form.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{ParseRomanKind, atoi_roman};
+/// 
+/// assert_eq!(atoi_roman("MCMXCIV"), Ok(1994));
+/// assert_eq!(atoi_roman("xiv"), Ok(14));
+/// assert_eq!(atoi_roman("Ⅻ"), Ok(12));
+/// assert_eq!(atoi_roman("V\u{305}I"), Ok(5001));
+/// assert_eq!(atoi_roman(""), Err(ParseRomanKind::Empty));
+/// assert_eq!(atoi_roman("XIZ"), Err(ParseRomanKind::InvalidCharacter));
+/// assert_eq!(atoi_roman("IIII"), Err(ParseRomanKind::NonCanonical));
+/// assert_eq!(atoi_roman("IM"), Err(ParseRomanKind::NonCanonical));
+/// assert_eq!(atoi_roman("Xi"), Err(ParseRomanKind::NonCanonical));
+/// ```
+pub fn atoi_roman(o: &str) -> Result<usize, ParseRomanKind> {
+	if o.is_empty() {
+		return Err(ParseRomanKind::Empty);
+	}
+	let mut letters = String::new();
+	let mut lower = false;
+	let mut upper = false;
+	for char in o.chars() {
+		match char {
+			COMBINING_OVERLINE => {
+				if !letters.ends_with(|e: char| e.is_ascii_uppercase()) {
+					return Err(ParseRomanKind::InvalidCharacter);
+				}
+				letters.push(char);
+			},
+			'I' | 'V' | 'X' | 'L' | 'C' | 'D' | 'M' => {
+				upper = true;
+				letters.push(char);
+			},
+			'i' | 'v' | 'x' | 'l' | 'c' | 'd' | 'm' => {
+				lower = true;
+				letters.push(char.to_ascii_uppercase());
+			},
+			'\u{2160}'..='\u{217F}' => {
+				let mut index = char as usize - 0x2160;
+				if index >= 0x10 {
+					lower = true;
+					index -= 0x10;
+				} else {
+					upper = true;
+				}
+				if index < 12 {
+					letters.push_str(&roman_letters(index + 1, RomanNotation::Standard));
+				} else {
+					letters.push(['L', 'C', 'D', 'M'][index - 12]);
+				}
+			},
+			_ => return Err(ParseRomanKind::InvalidCharacter),
+		}
+	}
+	if lower && upper {
+		return Err(ParseRomanKind::NonCanonical);
+	}
+	let mut values = Vec::new();
+	for char in letters.chars() {
+		if char == COMBINING_OVERLINE {
+			if let Some(last) = values.last_mut() {
+				*last *= 1000;
+			}
+		} else {
+			values.push(roman_letter_value(char));
+		}
+	}
+	let mut result = 0isize;
+	for (index, value) in values.iter().enumerate() {
+		if values.get(index + 1).is_some_and(|next| next > value) {
+			result = result.saturating_sub(*value as isize);
+		} else {
+			result = result.saturating_add(*value as isize);
+		}
+	}
+	let result = usize::try_from(result).map_err(|_| ParseRomanKind::NonCanonical)?;
+	let canonical = itoa_roman(result, RomanOptions::new(RomanNotation::Standard, false, true), Case::Upper);
+	if canonical.as_deref() != Some(letters.as_str()) {
+		return Err(ParseRomanKind::NonCanonical);
+	}
+	Ok(result)
+}
+
+/// Locale representation. Affects locale-dependent formatting, such as ordinal suffixes and separators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Locale {
+	/// English (`1st`, `2nd`, `3rd`, `4th`).
+	English,
+	
+	/// French (`1er`, `2e`, `3e`).
+	French,
+	
+	/// German (`1.`, `2.`, `3.`).
+	German,
+	
+	/// Spanish (`1.º`, `2.º`, `3.º`).
+	Spanish,
+}
+
+impl Locale {
+	/// Returns ordinal suffix for number. Only absolute value of number matters.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::converters::Locale;
+	/// 
+	/// assert_eq!(Locale::English.ordinal_suffix(1), "st");
+	/// assert_eq!(Locale::English.ordinal_suffix(12), "th");
+	/// assert_eq!(Locale::English.ordinal_suffix(22), "nd");
+	/// assert_eq!(Locale::French.ordinal_suffix(1), "er");
+	/// assert_eq!(Locale::French.ordinal_suffix(21), "e");
+	/// ```
+	pub fn ordinal_suffix(&self, number: usize) -> &'static str {
+		match self {
+			Locale::English => match (number % 10, number % 100) {
+				(_, 11..=13) => "th",
+				(1, _) => "st",
+				(2, _) => "nd",
+				(3, _) => "rd",
+				_ => "th",
+			},
+			Locale::French => if number == 1 {
+				"er"
+			} else {
+				"e"
+			},
+			Locale::German => ".",
+			Locale::Spanish => ".º",
+		}
+	}
+	
+	/// Returns decimal separator.
+	pub fn decimal_separator(&self) -> char {
+		match self {
+			Locale::English => '.',
+			_ => ',',
+		}
+	}
+	
+	/// Returns digit grouping (thousands) separator.
+	pub fn grouping_separator(&self) -> char {
+		match self {
+			Locale::English => ',',
+			Locale::French => ' ',
+			Locale::German | Locale::Spanish => '.',
+		}
+	}
+}
+
+/// Groups digits from the right, inserting `separator` between groups of `size` digits.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::group_digits;
+/// 
+/// assert_eq!(group_digits("1234567", ',', 3), "1,234,567");
+/// assert_eq!(group_digits("123", ',', 3), "123");
+/// assert_eq!(group_digits("11110000", '_', 4), "1111_0000");
+/// ```
+pub fn group_digits(
+	digits: &str,
+	separator: char,
+	size: usize,
+) -> String {
+	let length = digits.chars().count();
+	let mut result = String::new();
+	for (index, char) in digits.chars().enumerate() {
+		if index != 0 && (length - index).is_multiple_of(size) {
+			result.push(separator);
+		}
+		result.push(char);
+	}
+	result
+}
+
+/// Converts signed integers into decimal Strings with ordinal suffix.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Locale, itoa_ordinal};
+/// 
+/// assert_eq!(itoa_ordinal(1, Locale::English), "1st");
+/// assert_eq!(itoa_ordinal(22, Locale::English), "22nd");
+/// assert_eq!(itoa_ordinal(113, Locale::English), "113th");
+/// assert_eq!(itoa_ordinal(1, Locale::French), "1er");
+/// assert_eq!(itoa_ordinal(2, Locale::French), "2e");
+/// assert_eq!(itoa_ordinal(1, Locale::Spanish), "1.º");
+/// assert_eq!(itoa_ordinal(-3, Locale::English), "-3rd");
+/// ```
+pub fn itoa_ordinal(
+	number: isize,
+	locale: Locale,
+) -> String {
+	let mut result = itoa_signed(number, Base::new(10), Case::Lower);
+	result.push_str(locale.ordinal_suffix(number.unsigned_abs()));
+	result
+}
+
+/// Returned by `atof` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFloatKind {
+	/// Empty string.
+	Empty,
+	
+	/// Invalid character in the start of string.
+	InvalidCharacter,
+}
+
+/// Converts string to floating point number, returning `Ok(f64)` if successfully parsed, otherwise returns `Err(ParseFloatKind)` on error.
+/// Like C `atof`, parses the longest prefix which looks like a number (`-1.5e3`, `.5`, `inf`, `nan`) and ignores the rest.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{ParseFloatKind, atof};
+/// 
+/// assert_eq!(atof("3.14"), Ok(3.14));
+/// assert_eq!(atof("-1.5e3"), Ok(-1500.0));
+/// assert_eq!(atof(".5h"), Ok(0.5));
+/// assert_eq!(atof("2e"), Ok(2.0));
+/// assert_eq!(atof("-inf"), Ok(f64::NEG_INFINITY));
+/// assert_eq!(atof(""), Err(ParseFloatKind::Empty));
+/// assert_eq!(atof("x1"), Err(ParseFloatKind::InvalidCharacter));
+/// ```
+pub fn atof(o: &str) -> Result<f64, ParseFloatKind> {
+	if o.is_empty() {
+		return Err(ParseFloatKind::Empty);
+	}
+	let bytes = o.as_bytes();
+	let mut position = 0;
+	if position < bytes.len() && (bytes[position] == b'-' || bytes[position] == b'+') {
+		position += 1;
+	}
+	let unsigned = &o[position..];
+	for word in ["infinity", "inf", "nan"] {
+		/* input may have multi-byte character where word ends, so it is compared by bytes */
+		if unsigned.as_bytes().get(..word.len()).is_some_and(|e| e.eq_ignore_ascii_case(word.as_bytes())) {
+			return o[..position + word.len()]
+				.parse::<f64>()
+				.map_err(|_| ParseFloatKind::InvalidCharacter);
+		}
+	}
+	let mut digits = false;
+	while position < bytes.len() && bytes[position].is_ascii_digit() {
+		position += 1;
+		digits = true;
+	}
+	if position < bytes.len() && bytes[position] == b'.' {
+		position += 1;
+		while position < bytes.len() && bytes[position].is_ascii_digit() {
+			position += 1;
+			digits = true;
+		}
+	}
+	if !digits {
+		return Err(ParseFloatKind::InvalidCharacter);
+	}
+	if position < bytes.len() && (bytes[position] == b'e' || bytes[position] == b'E') {
+		let mut exponent = position + 1;
+		if exponent < bytes.len() && (bytes[exponent] == b'-' || bytes[exponent] == b'+') {
+			exponent += 1;
+		}
+		if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+			position = exponent;
+			while position < bytes.len() && bytes[position].is_ascii_digit() {
+				position += 1;
+			}
+		}
+	}
+	o[..position]
+		.parse::<f64>()
+		.map_err(|_| ParseFloatKind::InvalidCharacter)
+}
+
+/// Rounding mode for decimal operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RoundingMode {
+	/// Round to nearest, ties to even digit (banker's rounding).
+	HalfEven,
+	
+	/// Round to nearest, ties away from zero.
+	HalfUp,
+	
+	/// Round towards zero (drop extra digits).
+	Truncate,
+	
+	/// Round towards positive infinity.
+	Ceiling,
+	
+	/// Round towards negative infinity.
+	Floor,
+}
+
+impl RoundingMode {
+	/// Tells whether magnitude of number should be incremented after dropping digits.
+	/// `first` is the first dropped digit, `rest` tells whether any non-zero digit follows it,
+	/// `odd` tells whether the last kept digit is odd.
+	fn rounds_away(
+		&self,
+		negative: bool,
+		first: u8,
+		rest: bool,
+		odd: bool,
+	) -> bool {
+		let inexact = first != 0 || rest;
+		match self {
+			RoundingMode::HalfEven => first > 5 || (first == 5 && (rest || odd)),
+			RoundingMode::HalfUp => first >= 5,
+			RoundingMode::Truncate => false,
+			RoundingMode::Ceiling => inexact && !negative,
+			RoundingMode::Floor => inexact && negative,
+		}
+	}
+}
+
+/// Fixed-point decimal number. Value is `mantissa / 10^scale`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Decimal, RoundingMode};
+/// 
+/// let price = Decimal::new(123456, 3); /* 123.456 */
+/// assert_eq!(price.rescale(2, RoundingMode::HalfEven), Some(Decimal::new(12346, 2)));
+/// assert_eq!(price.rescale(2, RoundingMode::Truncate), Some(Decimal::new(12345, 2)));
+/// assert_eq!(price.rescale(4, RoundingMode::Truncate), Some(Decimal::new(1234560, 4)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decimal {
+	/// Digits of number, including fractional ones.
+	pub mantissa: i128,
+	
+	/// Number of fractional digits.
+	pub scale: u8,
+}
+
+impl Decimal {
+	/// Constructs Decimal.
+	pub fn new(
+		mantissa: i128,
+		scale: u8,
+	) -> Decimal {
+		Decimal {
+			mantissa,
+			scale,
+		}
+	}
+	
+	/// Returns same value with different scale, rounding if digits are dropped.
+	/// Returns `None` on overflow.
+	pub fn rescale(
+		&self,
+		scale: u8,
+		rounding: RoundingMode,
+	) -> Option<Decimal> {
+		if scale >= self.scale {
+			let factor = 10i128.checked_pow((scale - self.scale) as u32)?;
+			return Some(Decimal::new(self.mantissa.checked_mul(factor)?, scale));
+		}
+		let (quotient, first, rest) = match 10i128.checked_pow((self.scale - scale) as u32) {
+			Some(divisor) => {
+				let remainder = (self.mantissa % divisor).unsigned_abs();
+				let step = divisor.unsigned_abs() / 10;
+				(self.mantissa / divisor, (remainder / step) as u8, !remainder.is_multiple_of(step))
+			},
+			None => {
+				/* every digit is dropped, the first one is non-zero only if it is 10^38 digit */
+				let magnitude = self.mantissa.unsigned_abs();
+				let step = 10u128.pow(38);
+				if self.scale - scale == 39 {
+					(0, (magnitude / step) as u8, !magnitude.is_multiple_of(step))
+				} else {
+					(0, 0, magnitude != 0)
+				}
+			},
+		};
+		let away = rounding.rounds_away(self.mantissa < 0, first, rest, quotient % 2 != 0);
+		let mantissa = if !away {
+			quotient
+		} else if self.mantissa < 0 {
+			quotient.checked_sub(1)?
+		} else {
+			quotient.checked_add(1)?
+		};
+		Some(Decimal::new(mantissa, scale))
+	}
+}
+
+/// Notation of numbers rounded to significant figures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignificantNotation {
+	/// Positional notation, e.g. `12300` or `0.00123`.
+	Positional,
+	
+	/// Scientific notation, e.g. `1.23e4` or `1.23e-3`.
+	Scientific,
+}
+
+/// Rounds decimal digits of value `d.ddd × 10^exponent` to `figures` digits, returning new digits and exponent.
+fn round_digits(
+	negative: bool,
+	digits: &str,
+	exponent: isize,
+	figures: usize,
+	rounding: RoundingMode,
+) -> (String, isize) {
+	let mut result = digits.as_bytes().to_vec();
+	let mut exponent = exponent;
+	if result.len() > figures {
+		let dropped = result.split_off(figures);
+		let rest = dropped[1..].iter().any(|e| *e != b'0');
+		let odd = result.last().is_some_and(|e| (e - b'0') % 2 == 1);
+		if rounding.rounds_away(negative, dropped[0] - b'0', rest, odd) {
+			let mut index = result.len();
+			loop {
+				if index == 0 {
+					/* 999 became 1000 */
+					result.insert(0, b'1');
+					result.pop();
+					exponent += 1;
+					break;
+				}
+				index -= 1;
+				if result[index] == b'9' {
+					result[index] = b'0';
+				} else {
+					result[index] += 1;
+					break;
+				}
+			}
+		}
+	}
+	result.resize(figures, b'0');
+	(String::from_utf8(result).unwrap(), exponent)
+}
+
+/// Writes significant digits of value `d.ddd × 10^exponent` in given notation.
+fn significant_text(
+	negative: bool,
+	digits: &str,
+	exponent: isize,
+	notation: SignificantNotation,
+) -> String {
+	let mut result = if negative {
+		"-".to_string()
+	} else {
+		String::new()
+	};
+	match notation {
+		SignificantNotation::Scientific => {
+			result.push_str(&digits[..1]);
+			if digits.len() > 1 {
+				result.push('.');
+				result.push_str(&digits[1..]);
+			}
+			result.push('e');
+			result.push_str(&itoa_signed(exponent, Base::new(10), Case::Lower));
+		},
+		SignificantNotation::Positional if exponent < 0 => {
+			result.push_str("0.");
+			result.push_str(&"0".repeat((-exponent - 1) as usize));
+			result.push_str(digits);
+		},
+		SignificantNotation::Positional => {
+			let integer = exponent as usize + 1;
+			if integer >= digits.len() {
+				result.push_str(digits);
+				result.push_str(&"0".repeat(integer - digits.len()));
+			} else {
+				result.push_str(&digits[..integer]);
+				result.push('.');
+				result.push_str(&digits[integer..]);
+			}
+		},
+	}
+	result
+}
+
+/// Converts integer into String rounded to `figures` significant figures (at least one).
+/// Rounding is done on decimal digits, so it's exact.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{RoundingMode, SignificantNotation, itoa_significant};
+/// 
+/// assert_eq!(itoa_significant(12345, 3, RoundingMode::HalfEven, SignificantNotation::Positional), "12300");
+/// assert_eq!(itoa_significant(12345, 3, RoundingMode::HalfEven, SignificantNotation::Scientific), "1.23e4");
+/// assert_eq!(itoa_significant(12350, 3, RoundingMode::HalfEven, SignificantNotation::Positional), "12400");
+/// assert_eq!(itoa_significant(12301, 3, RoundingMode::Ceiling, SignificantNotation::Positional), "12400");
+/// assert_eq!(itoa_significant(-12301, 3, RoundingMode::Floor, SignificantNotation::Positional), "-12400");
+/// ```
+pub fn itoa_significant(
+	number: i128,
+	figures: usize,
+	rounding: RoundingMode,
+	notation: SignificantNotation,
+) -> String {
+	let digits = number.unsigned_abs().to_string();
+	let (digits, exponent) = round_digits(number < 0, &digits, digits.len() as isize - 1, figures.max(1), rounding);
+	significant_text(number < 0, &digits, exponent, notation)
+}
+
+/// Converts floating point number into String rounded to `figures` significant figures (at least one).
+/// Rounding is done on the shortest decimal representation of number (the one `{}` prints),
+/// so `2.675` rounds to `2.68` with `RoundingMode::HalfUp` even though its binary value is slightly less.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{RoundingMode, SignificantNotation, ftoa_significant};
+/// 
+/// assert_eq!(ftoa_significant(2.675, 3, RoundingMode::HalfUp, SignificantNotation::Positional), "2.68");
+/// assert_eq!(ftoa_significant(0.125, 2, RoundingMode::HalfEven, SignificantNotation::Positional), "0.12");
+/// assert_eq!(ftoa_significant(0.125, 2, RoundingMode::HalfUp, SignificantNotation::Positional), "0.13");
+/// assert_eq!(ftoa_significant(12345.0, 3, RoundingMode::HalfEven, SignificantNotation::Scientific), "1.23e4");
+/// assert_eq!(ftoa_significant(0.0001234, 2, RoundingMode::Floor, SignificantNotation::Scientific), "1.2e-4");
+/// assert_eq!(ftoa_significant(1.5, 4, RoundingMode::HalfEven, SignificantNotation::Positional), "1.500");
+/// ```
+pub fn ftoa_significant(
+	number: f64,
+	figures: usize,
+	rounding: RoundingMode,
+	notation: SignificantNotation,
+) -> String {
+	if !number.is_finite() {
+		return ftoa(number, 0);
+	}
+	let negative = number.is_sign_negative() && number != 0.0;
+	let scientific = format!("{:e}", number.abs());
+	let (mantissa, exponent) = scientific
+		.split_once('e')
+		.unwrap();
+	let digits = mantissa.replace('.', "");
+	let exponent = atoi_signed(exponent, Base::new(10), false).unwrap_or(0);
+	let (digits, exponent) = round_digits(negative, &digits, exponent, figures.max(1), rounding);
+	significant_text(negative, &digits, exponent, notation)
+}
+
+/// Returned by `parse_decimal` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseDecimalKind {
+	/// Empty string.
+	Empty,
+	
+	/// Invalid character or misplaced sign or decimal point.
+	InvalidCharacter,
+	
+	/// Overflow.
+	Overflow,
+}
+
+/// Converts string to decimal exactly, returning `Ok(Decimal)` if successfully parsed, otherwise returns `Err(ParseDecimalKind)` on error.
+/// Scale of result is the number of digits after decimal point, trailing zeros are kept.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Decimal, ParseDecimalKind, parse_decimal};
+/// 
+/// assert_eq!(parse_decimal("1234.5600"), Ok(Decimal::new(12345600, 4)));
+/// assert_eq!(parse_decimal("-0.1"), Ok(Decimal::new(-1, 1)));
+/// assert_eq!(parse_decimal("42"), Ok(Decimal::new(42, 0)));
+/// assert_eq!(parse_decimal("1.2.3"), Err(ParseDecimalKind::InvalidCharacter));
+/// ```
+pub fn parse_decimal(o: &str) -> Result<Decimal, ParseDecimalKind> {
+	if o.is_empty() {
+		return Err(ParseDecimalKind::Empty);
+	}
+	let (negative, digits) = match o.strip_prefix('-') {
+		Some(digits) => (true, digits),
+		None => (false, o.strip_prefix('+').unwrap_or(o)),
+	};
+	let mut mantissa = 0i128;
+	let mut scale = 0u8;
+	let mut point = false;
+	let mut success = false;
+	for char in digits.chars() {
+		if char == '.' && !point {
+			point = true;
+			continue;
+		}
+		let digit = char
+			.to_digit(10)
+			.ok_or(ParseDecimalKind::InvalidCharacter)? as i128;
+		success = true;
+		mantissa = mantissa
+			.checked_mul(10)
+			.and_then(|e| if negative {
+				e.checked_sub(digit)
+			} else {
+				e.checked_add(digit)
+			})
+			.ok_or(ParseDecimalKind::Overflow)?;
+		if point {
+			scale = scale
+				.checked_add(1)
+				.ok_or(ParseDecimalKind::Overflow)?;
+		}
+	}
+	if !success {
+		return Err(ParseDecimalKind::InvalidCharacter);
+	}
+	Ok(Decimal::new(mantissa, scale))
+}
+
+/// Converts decimal to String with `scale` digits after decimal point, using separators of `locale`.
+/// Returns `None` if rescaled value overflows.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Decimal, Locale, RoundingMode, format_decimal};
+/// 
+/// let amount = Decimal::new(123456785, 5); /* 1234.56785 */
+/// assert_eq!(format_decimal(amount, 2, RoundingMode::HalfEven, Locale::English, false), Some("1234.57".to_string()));
+/// assert_eq!(format_decimal(amount, 4, RoundingMode::HalfEven, Locale::English, true), Some("1,234.5678".to_string()));
+/// assert_eq!(format_decimal(amount, 4, RoundingMode::HalfUp, Locale::German, true), Some("1.234,5679".to_string()));
+/// assert_eq!(format_decimal(amount, 0, RoundingMode::Truncate, Locale::French, true), Some("1 234".to_string()));
+/// ```
+pub fn format_decimal(
+	decimal: Decimal,
+	scale: u8,
+	rounding: RoundingMode,
+	locale: Locale,
+	grouping: bool,
+) -> Option<String> {
+	let decimal = decimal.rescale(scale, rounding)?;
+	let digits = format_text(&decimal.mantissa.unsigned_abs().to_string(),
+		FormatOptions::new(FormatDirection::Right(scale as usize + 1), Some('0')));
+	let (integer, fraction) = digits.split_at(digits.len() - scale as usize);
+	let mut result = String::new();
+	if decimal.mantissa < 0 {
+		result.push('-');
+	}
+	if grouping {
+		result.push_str(&group_digits(integer, locale.grouping_separator(), 3));
+	} else {
+		result.push_str(integer);
+	}
+	if !fraction.is_empty() {
+		result.push(locale.decimal_separator());
+		result.push_str(fraction);
+	}
+	Some(result)
+}
+
+/// Converts positive integer into String in bijective numeration over given alphabet, in which
+/// first letter means one and there is no zero digit. Returns `None` if number is zero or alphabet is empty.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::itoa_bijective;
+/// 
+/// let alphabet = ['1', '2'];
+/// assert_eq!(itoa_bijective(1, &alphabet), Some("1".to_string()));
+/// assert_eq!(itoa_bijective(3, &alphabet), Some("11".to_string()));
+/// assert_eq!(itoa_bijective(6, &alphabet), Some("22".to_string()));
+/// assert_eq!(itoa_bijective(0, &alphabet), None);
+/// ```
+pub fn itoa_bijective(
+	number: usize,
+	alphabet: &[char],
+) -> Option<String> {
+	if number == 0 || alphabet.is_empty() {
+		return None;
+	}
+	let base = alphabet.len();
+	let mut number = number;
+	let mut result = Vec::new();
+	while number != 0 {
+		number -= 1;
+		result.push(alphabet[number % base]);
+		number /= base;
+	}
+	Some(result
+		.iter()
+		.rev()
+		.collect::<String>())
+}
+
+/// Converts string in bijective numeration over given alphabet to number.
+/// Unlike `atoi_unsigned`, every character must be a letter of alphabet.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{ParseIntKind, atoi_bijective};
+/// 
+/// let alphabet = ['1', '2'];
+/// assert_eq!(atoi_bijective("11", &alphabet), Ok(3));
+/// assert_eq!(atoi_bijective("22", &alphabet), Ok(6));
+/// assert_eq!(atoi_bijective("10", &alphabet), Err(ParseIntKind::InvalidCharacter));
+/// assert_eq!(atoi_bijective("", &alphabet), Err(ParseIntKind::Empty));
+/// ```
+pub fn atoi_bijective(
+	o: &str,
+	alphabet: &[char],
+) -> Result<usize, ParseIntKind> {
+	if o.is_empty() {
+		return Err(ParseIntKind::Empty);
+	}
+	o
+		.chars()
+		.try_fold(0usize, |result, char| {
+			let position = alphabet
+				.iter()
+				.position(|&e| e == char)
+				.ok_or(ParseIntKind::InvalidCharacter)?;
+			result
+				.checked_mul(alphabet.len())
+				.and_then(|e| e.checked_add(position + 1))
+				.ok_or(ParseIntKind::Overflow)
+		})
+}
+
+/// Converts positive integer into spreadsheet column name (`A`, `Z`, `AA`, ...), returning `None` if number is zero.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Case, itoa_column};
+/// 
+/// assert_eq!(itoa_column(1, Case::Upper), Some("A".to_string()));
+/// assert_eq!(itoa_column(26, Case::Upper), Some("Z".to_string()));
+/// assert_eq!(itoa_column(27, Case::Upper), Some("AA".to_string()));
+/// assert_eq!(itoa_column(52, Case::Lower), Some("az".to_string()));
+/// assert_eq!(itoa_column(16384, Case::Upper), Some("XFD".to_string()));
+/// ```
+pub fn itoa_column(
+	number: usize,
+	case: Case,
+) -> Option<String> {
+	itoa_bijective(number, &case.alphabet()[10..])
+}
+
+/// Converts spreadsheet column name to number (case-insensitive).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::atoi_column;
+/// 
+/// assert_eq!(atoi_column("A"), Ok(1));
+/// assert_eq!(atoi_column("az"), Ok(52));
+/// assert_eq!(atoi_column("XFD"), Ok(16384));
+/// ```
+pub fn atoi_column(o: &str) -> Result<usize, ParseIntKind> {
+	atoi_bijective(&o.to_ascii_uppercase(), &Case::Upper.alphabet()[10..])
+}
+
+/// Spreadsheet cell reference, with row and column starting from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellReference {
+	/// Row.
+	pub row: usize,
+	
+	/// Column.
+	pub column: usize,
+	
+	/// Whether row is absolute (`A$1`).
+	pub absolute_row: bool,
+	
+	/// Whether column is absolute (`$A1`).
+	pub absolute_column: bool,
+}
+
+impl CellReference {
+	/// Constructs relative CellReference.
+	pub fn new(
+		row: usize,
+		column: usize,
+	) -> CellReference {
+		CellReference {
+			row,
+			column,
+			absolute_row: false,
+			absolute_column: false,
+		}
+	}
+}
+
+/// Spreadsheet cell reference notation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellNotation {
+	/// Column name followed by row, e.g. `B3` or `$B$3`.
+	A1,
+	
+	/// Row and column numbers, e.g. `R3C2`. Only absolute references are supported.
+	R1C1,
+}
+
+/// Formats cell reference in given notation, returning `None` if row or column is zero.
+/// R1C1 references are always absolute, so absolute flags are ignored.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Case, CellNotation, CellReference, format_cell};
+/// 
+/// let mut cell = CellReference::new(3, 28);
+/// assert_eq!(format_cell(&cell, CellNotation::A1, Case::Upper), Some("AB3".to_string()));
+/// assert_eq!(format_cell(&cell, CellNotation::R1C1, Case::Upper), Some("R3C28".to_string()));
+/// cell.absolute_column = true;
+/// assert_eq!(format_cell(&cell, CellNotation::A1, Case::Lower), Some("$ab3".to_string()));
+/// ```
+pub fn format_cell(
+	cell: &CellReference,
+	notation: CellNotation,
+	case: Case,
+) -> Option<String> {
+	if cell.row == 0 {
+		return None;
+	}
+	let row = itoa_unsigned(cell.row, Base::new(10), Case::Lower);
+	Some(match notation {
+		CellNotation::A1 => {
+			let column = itoa_column(cell.column, case)?;
+			let dollar = |absolute: bool| if absolute {
+				"$"
+			} else {
+				""
+			};
+			format!("{}{}{}{}", dollar(cell.absolute_column), column, dollar(cell.absolute_row), row)
+		},
+		CellNotation::R1C1 => {
+			if cell.column == 0 {
+				return None;
+			}
+			let column = itoa_unsigned(cell.column, Base::new(10), Case::Lower);
+			match case {
+				Case::Lower => format!("r{}c{}", row, column),
+				Case::Upper => format!("R{}C{}", row, column),
+			}
+		},
+	})
+}
+
+/// Returned by `parse_cell` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseCellKind {
+	/// Empty string.
+	Empty,
+	
+	/// Invalid character, missing part, or zero row or column.
+	InvalidCharacter,
+	
+	/// Overflow.
+	Overflow,
+}
+
+/// Parses positive decimal number without leading zeros, spending whole string.
+fn parse_cell_number(o: &str) -> Result<usize, ParseCellKind> {
+	if o.is_empty() || o.starts_with('0') || !o.bytes().all(|e| e.is_ascii_digit()) {
+		return Err(ParseCellKind::InvalidCharacter);
+	}
+	atoi_unsigned(o, Base::new(10), false).map_err(|_| ParseCellKind::Overflow)
+}
+
+/// Parses cell reference in given notation (case-insensitive).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{CellNotation, CellReference, ParseCellKind, parse_cell};
+/// 
+/// assert_eq!(parse_cell("AB3", CellNotation::A1), Ok(CellReference::new(3, 28)));
+/// assert_eq!(parse_cell("$a$1", CellNotation::A1).map(|e| (e.absolute_row, e.absolute_column)), Ok((true, true)));
+/// assert_eq!(parse_cell("R3C28", CellNotation::R1C1).map(|e| (e.row, e.column)), Ok((3, 28)));
+/// assert_eq!(parse_cell("A0", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+/// ```
+pub fn parse_cell(
+	o: &str,
+	notation: CellNotation,
+) -> Result<CellReference, ParseCellKind> {
+	if o.is_empty() {
+		return Err(ParseCellKind::Empty);
+	}
+	match notation {
+		CellNotation::A1 => {
+			let (absolute_column, rest) = match o.strip_prefix('$') {
+				Some(rest) => (true, rest),
+				None => (false, o),
+			};
+			let letters = rest
+				.find(|c: char| !c.is_ascii_alphabetic())
+				.unwrap_or(rest.len());
+			let column = match atoi_column(&rest[..letters]) {
+				Ok(column) => column,
+				Err(ParseIntKind::Overflow) => return Err(ParseCellKind::Overflow),
+				Err(_) => return Err(ParseCellKind::InvalidCharacter),
+			};
+			let (absolute_row, rest) = match rest[letters..].strip_prefix('$') {
+				Some(rest) => (true, rest),
+				None => (false, &rest[letters..]),
+			};
+			Ok(CellReference {
+				row: parse_cell_number(rest)?,
+				column,
+				absolute_row,
+				absolute_column,
+			})
+		},
+		CellNotation::R1C1 => {
+			let rest = o
+				.strip_prefix(['R', 'r'])
+				.ok_or(ParseCellKind::InvalidCharacter)?;
+			let (row, column) = rest
+				.split_once(['C', 'c'])
+				.ok_or(ParseCellKind::InvalidCharacter)?;
+			Ok(CellReference {
+				row: parse_cell_number(row)?,
+				column: parse_cell_number(column)?,
+				absolute_row: true,
+				absolute_column: true,
+			})
+		},
+	}
+}
+
+/// Programming language, which integer literal syntax is used by `parse_literal`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiteralDialect {
+	/// Rust: `0xFF_u8`, `0o17`, `0b1010`, `1_000i64`, `b'A'`.
+	Rust,
+	
+	/// C: `0xFF`, `0777`, `0b1010` (C23), `1'000` (C23), `42UL`, `'A'`, `'\n'`.
+	C,
+	
+	/// Python: `0xFF`, `0o17`, `0b1010`, `1_000`.
+	Python,
+}
+
+/// Integer literal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegerLiteral {
+	/// Value.
+	pub value: u128,
+	
+	/// Base of digits, `None` for character literals.
+	pub base: Option<Base>,
+	
+	/// Type suffix, e.g. `u8` or `UL`.
+	pub suffix: Option<String>,
+}
+
+/// Returned by `parse_literal` if parsing of string failed.
+/// Positions are byte offsets into parsed string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseLiteralKind {
+	/// Empty string.
+	Empty,
+	
+	/// Invalid character at position (or missing character, if position is length of string).
+	InvalidCharacter(usize),
+	
+	/// Unknown type suffix, starting at position.
+	InvalidSuffix(usize),
+	
+	/// Value doesn't fit into literal type.
+	Overflow,
+}
+
+/// Returns the largest value of Rust integer type, named by literal suffix.
+fn rust_suffix_max(suffix: &str) -> Option<u128> {
+	Some(match suffix {
+		"u8" => u8::MAX as u128,
+		"u16" => u16::MAX as u128,
+		"u32" => u32::MAX as u128,
+		"u64" => u64::MAX as u128,
+		"u128" => u128::MAX,
+		"usize" => usize::MAX as u128,
+		"i8" => i8::MAX as u128,
+		"i16" => i16::MAX as u128,
+		"i32" => i32::MAX as u128,
+		"i64" => i64::MAX as u128,
+		"i128" => i128::MAX as u128,
+		"isize" => isize::MAX as u128,
+		_ => return None,
+	})
+}
+
+/// Checks C integer suffix: optional `u` combined with optional `l` or `ll` (same case), in any order.
+fn is_c_suffix(suffix: &str) -> bool {
+	let without_unsigned = suffix
+		.strip_prefix(['u', 'U'])
+		.or_else(|| suffix.strip_suffix(['u', 'U']))
+		.unwrap_or(suffix);
+	matches!(without_unsigned, "" | "l" | "L" | "ll" | "LL")
+}
+
+/// Parses character literal body (after opening quote at `start`) with escape sequences, up to closing quote.
+fn parse_character_literal(
+	o: &str,
+	start: usize,
+	dialect: LiteralDialect,
+) -> Result<u128, ParseLiteralKind> {
+	let mut iterator = o[start..]
+		.char_indices()
+		.map(|(index, c)| (index + start, c))
+		.peekable();
+	let value = match iterator.next() {
+		None => return Err(ParseLiteralKind::InvalidCharacter(o.len())),
+		Some((_, '\\')) => {
+			let (index, escape) = iterator
+				.next()
+				.ok_or(ParseLiteralKind::InvalidCharacter(o.len()))?;
+			match (dialect, escape) {
+				(_, 'n') => 0x0a,
+				(_, 'r') => 0x0d,
+				(_, 't') => 0x09,
+				(_, '\\') => 0x5c,
+				(_, '\'') => 0x27,
+				(_, '"') => 0x22,
+				(LiteralDialect::Rust, '0') => 0,
+				(LiteralDialect::Rust, 'x') => {
+					let mut value = 0;
+					for _ in 0..2 {
+						let (index, c) = iterator
+							.next()
+							.ok_or(ParseLiteralKind::InvalidCharacter(o.len()))?;
+						value = value * 16 + c
+							.to_digit(16)
+							.ok_or(ParseLiteralKind::InvalidCharacter(index))? as u128;
+					}
+					value
+				},
+				(LiteralDialect::C, 'a') => 0x07,
+				(LiteralDialect::C, 'b') => 0x08,
+				(LiteralDialect::C, 'f') => 0x0c,
+				(LiteralDialect::C, 'v') => 0x0b,
+				(LiteralDialect::C, '?') => 0x3f,
+				(LiteralDialect::C, '0'..='7') => {
+					let mut value = escape.to_digit(8).unwrap() as u128;
+					for _ in 0..2 {
+						match iterator.peek().and_then(|e| e.1.to_digit(8)) {
+							Some(digit) => value = value * 8 + digit as u128,
+							None => break,
+						}
+						iterator.next();
+					}
+					value
+				},
+				(LiteralDialect::C, 'x') => {
+					let mut value = 0u128;
+					let mut digits = 0;
+					while let Some(digit) = iterator.peek().and_then(|e| e.1.to_digit(16)) {
+						value = value * 16 + digit as u128;
+						if value > 0xff {
+							return Err(ParseLiteralKind::Overflow);
+						}
+						digits += 1;
+						iterator.next();
+					}
+					if digits == 0 {
+						return Err(ParseLiteralKind::InvalidCharacter(index + 1));
+					}
+					value
+				},
+				_ => return Err(ParseLiteralKind::InvalidCharacter(index)),
+			}
+		},
+		Some((index, c)) => {
+			let forbidden = c == '\'' || c == '\n' || (dialect == LiteralDialect::Rust && (c == '\r' || c == '\t'));
+			if !c.is_ascii() || forbidden {
+				return Err(ParseLiteralKind::InvalidCharacter(index));
+			}
+			c as u128
+		},
+	};
+	match iterator.next() {
+		Some((index, '\'')) if index + 1 == o.len() => Ok(value),
+		Some((index, '\'')) => Err(ParseLiteralKind::InvalidCharacter(index + 1)),
+		Some((index, _)) => Err(ParseLiteralKind::InvalidCharacter(index)),
+		None => Err(ParseLiteralKind::InvalidCharacter(o.len())),
+	}
+}
+
+/// Parses integer literal exactly as accepted by programming language, including base prefix,
+/// digit separators, type suffix and (in C and Rust) character literals. Sign is not part of literal.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Base, IntegerLiteral, LiteralDialect, ParseLiteralKind, parse_literal};
+/// 
+/// assert_eq!(parse_literal("0xFF_u8", LiteralDialect::Rust), Ok(IntegerLiteral {
+///     value: 255,
+///     base: Some(Base::new(16)),
+///     suffix: Some("u8".to_string()),
+/// }));
+/// assert_eq!(parse_literal("1_000i64", LiteralDialect::Rust).map(|e| e.value), Ok(1000));
+/// assert_eq!(parse_literal("0777", LiteralDialect::C).map(|e| (e.value, e.base)), Ok((511, Some(Base::new(8)))));
+/// assert_eq!(parse_literal("42UL", LiteralDialect::C).map(|e| e.suffix), Ok(Some("UL".to_string())));
+/// assert_eq!(parse_literal("'A'", LiteralDialect::C).map(|e| (e.value, e.base)), Ok((65, None)));
+/// assert_eq!(parse_literal("0o17", LiteralDialect::Python).map(|e| e.value), Ok(15));
+/// assert_eq!(parse_literal("1__000", LiteralDialect::Python), Err(ParseLiteralKind::InvalidCharacter(2)));
+/// assert_eq!(parse_literal("256u8", LiteralDialect::Rust), Err(ParseLiteralKind::Overflow));
+/// assert_eq!(parse_literal("0778", LiteralDialect::C), Err(ParseLiteralKind::InvalidCharacter(3)));
+/// ```
+pub fn parse_literal(
+	o: &str,
+	dialect: LiteralDialect,
+) -> Result<IntegerLiteral, ParseLiteralKind> {
+	if o.is_empty() {
+		return Err(ParseLiteralKind::Empty);
+	}
+	let character = match dialect {
+		LiteralDialect::Rust if o.starts_with("b'") => Some(2),
+		LiteralDialect::C if o.starts_with('\'') => Some(1),
+		_ => None,
+	};
+	if let Some(start) = character {
+		return Ok(IntegerLiteral {
+			value: parse_character_literal(o, start, dialect)?,
+			base: None,
+			suffix: None,
+		});
+	}
+	let prefix = o.get(..2).unwrap_or("");
+	let (base, start) = match (dialect, prefix) {
+		(LiteralDialect::Rust, "0x") => (16, 2),
+		(LiteralDialect::Rust, "0o") => (8, 2),
+		(LiteralDialect::Rust, "0b") => (2, 2),
+		(LiteralDialect::C, "0x" | "0X") => (16, 2),
+		(LiteralDialect::C, "0b" | "0B") => (2, 2),
+		(LiteralDialect::C, _) if o.starts_with('0') => (8, 0),
+		(LiteralDialect::Python, "0x" | "0X") => (16, 2),
+		(LiteralDialect::Python, "0o" | "0O") => (8, 2),
+		(LiteralDialect::Python, "0b" | "0B") => (2, 2),
+		_ => (10, 0),
+	};
+	let mut value = 0u128;
+	let mut digits = 0;
+	/* position of separator, which must be followed by digit */
+	let mut separator = None;
+	let mut end = o.len();
+	for (index, c) in o[start..].char_indices() {
+		let index = index + start;
+		if let Some(digit) = c.to_digit(base) {
+			value = value
+				.checked_mul(base as u128)
+				.and_then(|e| e.checked_add(digit as u128))
+				.ok_or(ParseLiteralKind::Overflow)?;
+			digits += 1;
+			separator = None;
+			continue;
+		}
+		match (dialect, c) {
+			(LiteralDialect::Rust, '_') if index != 0 => {},
+			(LiteralDialect::Python, '_') if separator.is_none() && (digits != 0 || start != 0) => separator = Some(index),
+			(LiteralDialect::C, '\'') if separator.is_none() && digits != 0 => separator = Some(index),
+			(LiteralDialect::Rust | LiteralDialect::Python, '_') | (LiteralDialect::C, '\'') => {
+				return Err(ParseLiteralKind::InvalidCharacter(index));
+			},
+			_ => {
+				end = index;
+				break;
+			},
+		}
+	}
+	if let Some(separator) = separator {
+		return Err(ParseLiteralKind::InvalidCharacter(separator));
+	}
+	if digits == 0 || o[end..].starts_with(|c: char| c.is_ascii_digit()) {
+		return Err(ParseLiteralKind::InvalidCharacter(end));
+	}
+	if dialect == LiteralDialect::Python && base == 10 && value != 0 && o.starts_with('0') {
+		/* leading zeros in decimal integer literals are not permitted */
+		return Err(ParseLiteralKind::InvalidCharacter(0));
+	}
+	let suffix = &o[end..];
+	match dialect {
+		LiteralDialect::Rust if !suffix.is_empty() => {
+			let max = rust_suffix_max(suffix).ok_or(ParseLiteralKind::InvalidSuffix(end))?;
+			if value > max {
+				return Err(ParseLiteralKind::Overflow);
+			}
+		},
+		LiteralDialect::Rust => {},
+		LiteralDialect::C => {
+			if !is_c_suffix(suffix) {
+				return Err(ParseLiteralKind::InvalidSuffix(end));
+			}
+			if value > u64::MAX as u128 {
+				return Err(ParseLiteralKind::Overflow);
+			}
+		},
+		LiteralDialect::Python if !suffix.is_empty() => return Err(ParseLiteralKind::InvalidCharacter(end)),
+		LiteralDialect::Python => {},
+	}
+	Ok(IntegerLiteral {
+		value,
+		base: Some(Base::new(base as i32)),
+		suffix: if suffix.is_empty() {
+			None
+		} else {
+			Some(suffix.to_string())
+		},
+	})
+}
+
 /// Same as C `atoi`.
 /// For some reason, you need `use rustrings::converters::{Base, atoi_signed};`.
 /// 
@@ -0,0 +1,263 @@
+//! Currency formatting.
+//! 
+//! This module contains formatter for monetary amounts on top of `Decimal`, with a small built-in table of currencies.
+
+use crate::converters::Decimal;
+use crate::converters::Locale;
+use crate::converters::RoundingMode;
+use crate::converters::format_decimal;
+use crate::format::FormatDirection;
+use crate::format::FormatOptions;
+use crate::format::format_text;
+
+/// Position of currency symbol relative to amount.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolPosition {
+	/// Symbol is written before amount, e.g. `$1.00`.
+	Before,
+	
+	/// Symbol is written after amount, e.g. `1,00 €`.
+	After,
+}
+
+/// Currency description.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Currency {
+	/// ISO 4217 code.
+	pub code: &'static str,
+	
+	/// Symbol.
+	pub symbol: &'static str,
+	
+	/// Number of digits after decimal point.
+	pub minor_units: u8,
+	
+	/// Position of symbol.
+	pub position: SymbolPosition,
+}
+
+impl Currency {
+	/// Finds currency in built-in table by ISO 4217 code (case-insensitive).
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::currency::Currency;
+	/// 
+	/// assert_eq!(Currency::from_code("usd").unwrap().symbol, "$");
+	/// assert_eq!(Currency::from_code("JPY").unwrap().minor_units, 0);
+	/// assert_eq!(Currency::from_code("XYZ"), None);
+	/// ```
+	pub fn from_code(code: &str) -> Option<Currency> {
+		CURRENCIES
+			.iter()
+			.find(|e| e.code.eq_ignore_ascii_case(code))
+			.cloned()
+	}
+}
+
+/// Built-in table of currencies.
+pub const CURRENCIES: [Currency; 14] = [
+	Currency { code: "USD", symbol: "$", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "EUR", symbol: "€", minor_units: 2, position: SymbolPosition::After },
+	Currency { code: "GBP", symbol: "£", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "JPY", symbol: "¥", minor_units: 0, position: SymbolPosition::Before },
+	Currency { code: "CNY", symbol: "¥", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "INR", symbol: "₹", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "CHF", symbol: "CHF", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "CAD", symbol: "$", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "AUD", symbol: "$", minor_units: 2, position: SymbolPosition::Before },
+	Currency { code: "SEK", symbol: "kr", minor_units: 2, position: SymbolPosition::After },
+	Currency { code: "RUB", symbol: "₽", minor_units: 2, position: SymbolPosition::After },
+	Currency { code: "KRW", symbol: "₩", minor_units: 0, position: SymbolPosition::Before },
+	Currency { code: "KWD", symbol: "KD", minor_units: 3, position: SymbolPosition::Before },
+	Currency { code: "BHD", symbol: "BD", minor_units: 3, position: SymbolPosition::Before },
+];
+
+/// How currency is denoted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurrencyDisplay {
+	/// Currency symbol, e.g. `$1,234.56`.
+	Symbol,
+	
+	/// ISO 4217 code, e.g. `USD 1,234.56`.
+	Code,
+}
+
+/// Currency formatting options.
+#[derive(Debug, Clone)]
+pub struct CurrencyOptions {
+	/// Locale of separators.
+	pub locale: Locale,
+	
+	/// How currency is denoted.
+	pub display: CurrencyDisplay,
+	
+	/// Write negative amounts in parentheses instead of minus sign.
+	pub accounting: bool,
+	
+	/// Rounding mode, used if amount has more digits than currency minor units.
+	pub rounding: RoundingMode,
+}
+
+impl CurrencyOptions {
+	/// Constructs CurrencyOptions.
+	pub fn new(
+		locale: Locale,
+		display: CurrencyDisplay,
+		accounting: bool,
+		rounding: RoundingMode,
+	) -> CurrencyOptions {
+		CurrencyOptions {
+			locale,
+			display,
+			accounting,
+			rounding,
+		}
+	}
+}
+
+impl Default for CurrencyOptions {
+	fn default() -> CurrencyOptions {
+		CurrencyOptions::new(Locale::English, CurrencyDisplay::Symbol, false, RoundingMode::HalfEven)
+	}
+}
+
+/// Formats monetary amount into text before decimal point and text from decimal point on,
+/// returning `None` on overflow. Amounts without minor units have only suffixes after decimal point.
+fn currency_parts(
+	amount: Decimal,
+	currency: &Currency,
+	options: &CurrencyOptions,
+) -> Option<(String, String)> {
+	let digits = format_decimal(amount, currency.minor_units, options.rounding, options.locale, true)?;
+	let (negative, digits) = match digits.strip_prefix('-') {
+		Some(digits) => (true, digits),
+		None => (false, digits.as_str()),
+	};
+	let (integer, fraction) = match digits.find(options.locale.decimal_separator()) {
+		Some(index) => digits.split_at(index),
+		None => (digits, ""),
+	};
+	let denotation = match options.display {
+		CurrencyDisplay::Symbol => currency.symbol,
+		CurrencyDisplay::Code => currency.code,
+	};
+	let spaced = options.display == CurrencyDisplay::Code
+		|| currency.position == SymbolPosition::After
+		|| denotation.chars().all(char::is_alphabetic);
+	let separator = if spaced {
+		" "
+	} else {
+		""
+	};
+	let (mut head, mut tail) = match currency.position {
+		SymbolPosition::Before => (format!("{}{}{}", denotation, separator, integer), fraction.to_string()),
+		SymbolPosition::After => (integer.to_string(), format!("{}{}{}", fraction, separator, denotation)),
+	};
+	if options.accounting {
+		/* non-negative amounts get space in place of closing parenthesis */
+		if negative {
+			head.insert(0, '(');
+			tail.push(')');
+		} else {
+			tail.push(' ');
+		}
+	} else if negative {
+		head.insert(0, '-');
+	}
+	Some((head, tail))
+}
+
+/// Formats monetary amount with grouped digits and currency symbol or code, returning `None` on overflow.
+/// Amount is rounded to minor units of currency.
+/// 
+/// With accounting negatives, non-negative amounts get trailing space in place of closing parenthesis,
+/// so right-aligned columns of amounts in one currency line up on the decimal point.
+/// Use `format_currency_column` to line up amounts in different currencies.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::{Decimal, Locale, RoundingMode};
+/// use rustrings::currency::{Currency, CurrencyDisplay, CurrencyOptions, format_currency};
+/// use rustrings::format::FormatDirection;
+/// 
+/// let usd = Currency::from_code("USD").unwrap();
+/// let eur = Currency::from_code("EUR").unwrap();
+/// let amount = Decimal::new(123456, 2);
+/// assert_eq!(format_currency(amount, &usd, CurrencyOptions::default(), FormatDirection::None), Some("$1,234.56".to_string()));
+/// assert_eq!(format_currency(amount, &eur,
+///     CurrencyOptions::new(Locale::French, CurrencyDisplay::Symbol, false, RoundingMode::HalfEven),
+///     FormatDirection::None), Some("1 234,56 €".to_string()));
+/// assert_eq!(format_currency(Decimal::new(-123456, 2), &usd,
+///     CurrencyOptions::new(Locale::English, CurrencyDisplay::Symbol, true, RoundingMode::HalfEven),
+///     FormatDirection::None), Some("($1,234.56)".to_string()));
+/// assert_eq!(format_currency(amount, &usd,
+///     CurrencyOptions::new(Locale::English, CurrencyDisplay::Code, false, RoundingMode::HalfEven),
+///     FormatDirection::None), Some("USD 1,234.56".to_string()));
+/// ```
+pub fn format_currency(
+	amount: Decimal,
+	currency: &Currency,
+	options: CurrencyOptions,
+	padding: FormatDirection,
+) -> Option<String> {
+	let (head, tail) = currency_parts(amount, currency, &options)?;
+	Some(format_text(&format!("{}{}", head, tail), FormatOptions::new(padding, None)))
+}
+
+/// Formats column of monetary amounts like `format_currency`, returning `None` on overflow.
+/// Text before and after decimal point is padded to common widths, so amounts line up on the decimal point
+/// whatever their minor units and symbol position. Lines have equal width and are then aligned with `padding`,
+/// `FormatDirection::Center` included.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::Decimal;
+/// use rustrings::currency::{Currency, CurrencyOptions, format_currency_column};
+/// use rustrings::format::FormatDirection;
+/// 
+/// let usd = Currency::from_code("USD").unwrap();
+/// let jpy = Currency::from_code("JPY").unwrap();
+/// let kwd = Currency::from_code("KWD").unwrap();
+/// let column = format_currency_column(&[
+///     (Decimal::new(123456, 2), &usd),
+///     (Decimal::new(500, 0), &jpy),
+///     (Decimal::new(-75, 1), &kwd),
+/// ], CurrencyOptions::default(), FormatDirection::Right(14)).unwrap();
+/// assert_eq!(column, [
+///     "    $1,234.56 ",
+///     "      ¥500    ",
+///     "     -KD 7.500",
+/// ]);
+/// ```
+pub fn format_currency_column(
+	amounts: &[(Decimal, &Currency)],
+	options: CurrencyOptions,
+	padding: FormatDirection,
+) -> Option<Vec<String>> {
+	let parts = amounts
+		.iter()
+		.map(|e| currency_parts(e.0, e.1, &options))
+		.collect::<Option<Vec<(String, String)>>>()?;
+	let head_width = parts.iter().map(|e| e.0.chars().count()).max().unwrap_or(0);
+	let tail_width = parts.iter().map(|e| e.1.chars().count()).max().unwrap_or(0);
+	Some(parts
+		.iter()
+		.map(|(head, tail)| {
+			let line = format!("{}{}",
+				format_text(head, FormatOptions::new(FormatDirection::Right(head_width), None)),
+				format_text(tail, FormatOptions::new(FormatDirection::Left(tail_width), None)));
+			match padding {
+				/* lines have equal width, so centered lines stay aligned */
+				FormatDirection::Center(width) => {
+					let extra = width.saturating_sub(head_width + tail_width);
+					format!("{}{}{}", " ".repeat(extra / 2), line, " ".repeat(extra - extra / 2))
+				},
+				_ => format_text(&line, FormatOptions::new(padding.clone(), None)),
+			}
+		})
+		.collect())
+}
@@ -1,8 +1,22 @@
 //! Lightweight formatting module. Allows to justify and fill string.
 
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::CharIndices;
+use std::sync::Arc;
+
 use crate::converters::Base;
 use crate::converters::Case;
+use crate::converters::Locale;
+use crate::converters::atoi_signed;
 use crate::converters::atoi_unsigned;
+use crate::converters::etoa;
+use crate::converters::ftoa;
+use crate::converters::group_digits;
 use crate::converters::itoa_signed;
 use crate::converters::itoa_unsigned;
 
@@ -32,8 +46,8 @@ impl FormatOptions {
 		filling: Option<char>,
 	) -> FormatOptions {
 		FormatOptions {
-			padding: padding,
-			filling: filling,
+			padding,
+			filling,
 		}
 	}
 	
@@ -81,7 +95,7 @@ impl FormatOptions {
 	}
 }
 
-/// Formats the text.
+/// Formats the text. Width is counted in characters, not bytes.
 /// 
 /// # Examples
 /// 
@@ -94,24 +108,25 @@ impl FormatOptions {
 ///     FormatOptions::new(FormatDirection::Left(15), Some('_'))), "Hello, world!__");
 /// assert_eq!(format_text(&"Hello, world!".to_string(),
 ///     FormatOptions::new(FormatDirection::Right(15), Some('_'))), "__Hello, world!");
+/// assert_eq!(format_text("€5", FormatOptions::new(FormatDirection::Right(4), None)), "  €5");
 /// ```
 pub fn format_text(
-	text: &String,
+	text: &str,
 	options: FormatOptions,
 ) -> String {
 	let filling = options
 		.filling
 		.unwrap_or(' ');
-	let mut result = text.clone();
+	let mut result = text.to_string();
 	match options.padding {
 		FormatDirection::Left(width) => {
 			let mut width = width;
-			let length = text.len();
+			let length = text.chars().count();
 			if width < length {
 				return result;
 			}
 			width -= length;
-			result.push_str(&String::from(filling).repeat(width).as_str());
+			result.push_str(String::from(filling).repeat(width).as_str());
 		},
 		/* reserved */
 		FormatDirection::Center(_width) => {
@@ -119,7 +134,7 @@ pub fn format_text(
 		},
 		FormatDirection::Right(width) => {
 			let mut width = width;
-			let length = text.len();
+			let length = text.chars().count();
 			if width < length {
 				return result;
 			}
@@ -149,6 +164,9 @@ pub enum CFormatArgument {
 	/// Represents signed 64-bit integer.
 	Int64(i64),
 	
+	/// Represents signed 128-bit integer.
+	Int128(i128),
+	
 	/// Represents signed machine word.
 	IntSize(isize),
 	
@@ -164,6 +182,9 @@ pub enum CFormatArgument {
 	/// Represents unsigned 64-bit integer.
 	UInt64(u64),
 	
+	/// Represents unsigned 128-bit integer.
+	UInt128(u128),
+	
 	/// Represents unsigned machine word.
 	UIntSize(usize),
 	
@@ -175,30 +196,293 @@ pub enum CFormatArgument {
 	
 	/// Represents unsigned 8-bit integer.
 	Pointer(*const u8),
+	
+	/// Represents single precision floating point number. Promoted to double precision, like in C.
+	Float32(f32),
+	
+	/// Represents double precision floating point number.
+	Float64(f64),
+	
+	/// Represents user value, formatted by custom conversion registered in `CFormatRegistry`.
+	Custom(Box<dyn CFormatCustom>),
+}
+
+impl CFormatArgument {
+	/// Returns variant of argument without its value.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::CFormatArgumentKind;
+	/// 
+	/// assert_eq!(CFormatArgument::Int32(42).kind(), CFormatArgumentKind::Int32);
+	/// assert_eq!(CFormatArgument::String("a".to_string()).kind(), CFormatArgumentKind::String);
+	/// ```
+	pub fn kind(&self) -> CFormatArgumentKind {
+		match self {
+			CFormatArgument::Int8(_) => CFormatArgumentKind::Int8,
+			CFormatArgument::Int16(_) => CFormatArgumentKind::Int16,
+			CFormatArgument::Int32(_) => CFormatArgumentKind::Int32,
+			CFormatArgument::Int64(_) => CFormatArgumentKind::Int64,
+			CFormatArgument::Int128(_) => CFormatArgumentKind::Int128,
+			CFormatArgument::IntSize(_) => CFormatArgumentKind::IntSize,
+			CFormatArgument::UInt8(_) => CFormatArgumentKind::UInt8,
+			CFormatArgument::UInt16(_) => CFormatArgumentKind::UInt16,
+			CFormatArgument::UInt32(_) => CFormatArgumentKind::UInt32,
+			CFormatArgument::UInt64(_) => CFormatArgumentKind::UInt64,
+			CFormatArgument::UInt128(_) => CFormatArgumentKind::UInt128,
+			CFormatArgument::UIntSize(_) => CFormatArgumentKind::UIntSize,
+			CFormatArgument::Character(_) => CFormatArgumentKind::Character,
+			CFormatArgument::String(_) => CFormatArgumentKind::String,
+			CFormatArgument::Pointer(_) => CFormatArgumentKind::Pointer,
+			CFormatArgument::Float32(_) => CFormatArgumentKind::Float32,
+			CFormatArgument::Float64(_) => CFormatArgumentKind::Float64,
+			CFormatArgument::Custom(_) => CFormatArgumentKind::Custom,
+		}
+	}
+}
+
+/// Variant of `CFormatArgument` without value. Used to report expected and supplied arguments in `FormatError`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CFormatArgumentKind {
+	/// `CFormatArgument::Int8`.
+	Int8,
+	
+	/// `CFormatArgument::Int16`.
+	Int16,
+	
+	/// `CFormatArgument::Int32`.
+	Int32,
+	
+	/// `CFormatArgument::Int64`.
+	Int64,
+	
+	/// `CFormatArgument::Int128`.
+	Int128,
+	
+	/// `CFormatArgument::IntSize`.
+	IntSize,
+	
+	/// `CFormatArgument::UInt8`.
+	UInt8,
+	
+	/// `CFormatArgument::UInt16`.
+	UInt16,
+	
+	/// `CFormatArgument::UInt32`.
+	UInt32,
+	
+	/// `CFormatArgument::UInt64`.
+	UInt64,
+	
+	/// `CFormatArgument::UInt128`.
+	UInt128,
+	
+	/// `CFormatArgument::UIntSize`.
+	UIntSize,
+	
+	/// `CFormatArgument::Character`.
+	Character,
+	
+	/// `CFormatArgument::String`.
+	String,
+	
+	/// `CFormatArgument::Pointer`.
+	Pointer,
+	
+	/// `CFormatArgument::Float32`.
+	Float32,
+	
+	/// `CFormatArgument::Float64`. Floating point conversions also accept `Float32`.
+	Float64,
+	
+	/// `CFormatArgument::Custom`.
+	Custom,
+}
+
+/// User value of `CFormatArgument::Custom`. Implemented for every `Clone` and `Debug` type,
+/// which handlers of custom conversions get back with `as_any().downcast_ref()`.
+pub trait CFormatCustom: Any + Debug {
+	/// Returns value as `Any`.
+	fn as_any(&self) -> &dyn Any;
+	
+	/// Returns boxed copy of value.
+	fn clone_box(&self) -> Box<dyn CFormatCustom>;
+}
+
+impl<T: Any + Debug + Clone> CFormatCustom for T {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+	
+	fn clone_box(&self) -> Box<dyn CFormatCustom> {
+		Box::new(self.clone())
+	}
+}
+
+impl Clone for Box<dyn CFormatCustom> {
+	fn clone(&self) -> Self {
+		/* dereferenced, so boxed value is copied instead of box */
+		(**self).clone_box()
+	}
+}
+
+/// Rust value, which can be passed to `c_format!` macro.
+pub trait CFormatValue: Into<CFormatArgument> {
+	/// Variant of `CFormatArgument` converted from value.
+	const KIND: CFormatArgumentKind;
+}
+
+/// Implements `From` and `CFormatValue` for type, converted to variant of `CFormatArgument`.
+macro_rules! c_format_value {
+	($type:ty, $variant:ident) => {
+		impl From<$type> for CFormatArgument {
+			fn from(value: $type) -> CFormatArgument {
+				CFormatArgument::$variant(value)
+			}
+		}
+		
+		impl CFormatValue for $type {
+			const KIND: CFormatArgumentKind = CFormatArgumentKind::$variant;
+		}
+	};
+}
+
+c_format_value!(i8, Int8);
+c_format_value!(i16, Int16);
+c_format_value!(i32, Int32);
+c_format_value!(i64, Int64);
+c_format_value!(i128, Int128);
+c_format_value!(isize, IntSize);
+c_format_value!(u8, UInt8);
+c_format_value!(u16, UInt16);
+c_format_value!(u32, UInt32);
+c_format_value!(u64, UInt64);
+c_format_value!(u128, UInt128);
+c_format_value!(usize, UIntSize);
+c_format_value!(char, Character);
+c_format_value!(String, String);
+c_format_value!(*const u8, Pointer);
+c_format_value!(f32, Float32);
+c_format_value!(f64, Float64);
+
+impl From<&str> for CFormatArgument {
+	fn from(value: &str) -> CFormatArgument {
+		CFormatArgument::String(value.to_string())
+	}
+}
+
+impl CFormatValue for &str {
+	const KIND: CFormatArgumentKind = CFormatArgumentKind::String;
+}
+
+impl From<&String> for CFormatArgument {
+	fn from(value: &String) -> CFormatArgument {
+		CFormatArgument::String(value.clone())
+	}
+}
+
+impl CFormatValue for &String {
+	const KIND: CFormatArgumentKind = CFormatArgumentKind::String;
+}
+
+/// Returned by `try_c_format` if formatting failed.
+/// `spec` is text of conversion specification, like `%5d`, starting at byte `offset` of format string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+	/// Conversion specification is not terminated, like trailing `%`.
+	Incomplete {
+		offset: usize,
+		spec: String,
+	},
+	
+	/// Unknown conversion, or length modifier which is not accepted by conversion.
+	InvalidSpecifier {
+		offset: usize,
+		spec: String,
+	},
+	
+	/// Conversion needs argument with `index`, which is not supplied.
+	MissingArgument {
+		offset: usize,
+		spec: String,
+		index: usize,
+	},
+	
+	/// Argument with `index` doesn't match conversion.
+	Mismatch {
+		offset: usize,
+		spec: String,
+		index: usize,
+		expected: CFormatArgumentKind,
+		supplied: CFormatArgumentKind,
+	},
+	
+	/// Positional (`%n$`, `*m$`) and sequential (`%`, `*`) arguments are mixed in format string.
+	MixedArguments {
+		offset: usize,
+		spec: String,
+	},
+	
+	/// Format string uses only `used` of `supplied` arguments.
+	ExtraArguments {
+		used: usize,
+		supplied: usize,
+	},
+	
+	/// Positional arguments skip argument with `index`, though later arguments are used.
+	SkippedArgument {
+		index: usize,
+	},
 }
 
 /// Represents type specifier inside `c_format` function.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CFormatTypeSpecifier {
-	/// Represents decimal i32 format specifier. (`d`, `i`)
+	/// Represents decimal i32 format specifier. (`d`, `i`, `w32d`)
 	Integer,
 	
-	/// Represents octal u32 format specifier. (`o`)
+	/// Represents octal u32 format specifier. (`o`, `w32o`)
 	Octal,
 	
-	/// Represents decimal u32 format specifier. (`u`)
+	/// Represents decimal u32 format specifier. (`u`, `w32u`)
 	Unsigned,
 	
-	/// Represents hexdecimal u32 format specifier. Uses lower-case letters. (`x`)
+	/// Represents hexdecimal u32 format specifier. Uses lower-case letters. (`x`, `w32x`)
 	LowerHex,
 	
-	/// Represents hexdecimal u32 format specifier. Uses upper-case letters. (`X`)
+	/// Represents hexdecimal u32 format specifier. Uses upper-case letters. (`X`, `w32X`)
 	UpperHex,
 	
+	/// Represents binary u32 format specifier. (`b`, `w32b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#b".to_string(), vec![CFormatArgument::UInt32(42)]), "0b101010");
+	/// ```
+	Binary,
+	
+	/// Represents binary u32 format specifier. Uses upper-case prefix. (`B`, `w32B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#B".to_string(), vec![CFormatArgument::UInt32(42)]), "0B101010");
+	/// ```
+	UpperBinary,
+	
 	/// Represents character format specifier. (`c`)
 	Character,
 	
-	/// Represents decimal i8 format specifier. (`hhi`)
+	/// Represents decimal i8 format specifier. (`hhi`, `w8i`, `wf8i`)
 	/// 
 	/// # Examples
 	/// 
@@ -210,7 +494,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ByteInt,
 	
-	/// Represents hexadecimal u8 format specifier. Uses lower-case letters. (`hhx`)
+	/// Represents hexadecimal u8 format specifier. Uses lower-case letters. (`hhx`, `w8x`, `wf8x`)
 	/// 
 	/// # Examples
 	/// 
@@ -222,7 +506,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ByteLowerHex,
 	
-	/// Represents hexadecimal u8 format specifier. Uses upper-case letters. (`hhX`)
+	/// Represents hexadecimal u8 format specifier. Uses upper-case letters. (`hhX`, `w8X`, `wf8X`)
 	/// 
 	/// # Examples
 	/// 
@@ -234,7 +518,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ByteUpperHex,
 	
-	/// Represents octal u8 format specifier. (`hho`)
+	/// Represents octal u8 format specifier. (`hho`, `w8o`, `wf8o`)
 	/// 
 	/// # Examples
 	/// 
@@ -246,7 +530,31 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ByteOctal,
 	
-	/// Represents unsigned u8 format specifier. (`hhu`) 
+	/// Represents binary u8 format specifier. (`hhb`, `w8b`, `wf8b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%08hhb".to_string(), vec![CFormatArgument::UInt8(42u8)]), "00101010");
+	/// ```
+	ByteBinary,
+	
+	/// Represents binary u8 format specifier. Uses upper-case prefix. (`hhB`, `w8B`, `wf8B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#hhB".to_string(), vec![CFormatArgument::UInt8(42u8)]), "0B101010");
+	/// ```
+	ByteUpperBinary,
+	
+	/// Represents unsigned u8 format specifier. (`hhu`, `w8u`, `wf8u`) 
 	/// 
 	/// # Examples
 	/// 
@@ -258,7 +566,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ByteUnsigned,
 	
-	/// Represents decimal i16 format specifier. (`hi`)
+	/// Represents decimal i16 format specifier. (`hi`, `w16i`)
 	/// 
 	/// # Examples
 	/// 
@@ -270,7 +578,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ShortInt,
 	
-	/// Represents hexadecimal u16 format specifier. Uses lower-case letters. (`hx`)
+	/// Represents hexadecimal u16 format specifier. Uses lower-case letters. (`hx`, `w16x`)
 	/// 
 	/// # Examples
 	/// 
@@ -283,7 +591,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ShortLowerHex,
 	
-	/// Represents hexadecimal u16 format specifier. Uses upper-case letters. (`hX`)
+	/// Represents hexadecimal u16 format specifier. Uses upper-case letters. (`hX`, `w16X`)
 	/// 
 	/// # Examples
 	/// 
@@ -296,7 +604,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ShortUpperHex,
 	
-	/// Represents octal u16 format specifier. (`ho`)
+	/// Represents octal u16 format specifier. (`ho`, `w16o`)
 	/// 
 	/// # Examples
 	/// 
@@ -309,7 +617,31 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ShortOctal,
 	
-	/// Represents decimal i16 format specifier. (`hu`)
+	/// Represents binary u16 format specifier. (`hb`, `w16b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%hb".to_string(), vec![CFormatArgument::UInt16(0xf00d)]), "1111000000001101");
+	/// ```
+	ShortBinary,
+	
+	/// Represents binary u16 format specifier. Uses upper-case prefix. (`hB`, `w16B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#hB".to_string(), vec![CFormatArgument::UInt16(0xf00d)]), "0B1111000000001101");
+	/// ```
+	ShortUpperBinary,
+	
+	/// Represents decimal i16 format specifier. (`hu`, `w16u`)
 	/// 
 	/// # Examples
 	/// 
@@ -322,7 +654,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	ShortUnsigned,
 	
-	/// Represents decimal isize format specifier. (`li`, `ld`)
+	/// Represents decimal isize format specifier. (`li`, `ld`, `zi`, `ti`, `wf16i`, `wf32i`)
 	/// 
 	/// # Examples
 	/// 
@@ -335,7 +667,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongInt,
 	
-	/// Represents hexadecimal usize format specifier. Uses lower-case letters. (`lx`)
+	/// Represents hexadecimal usize format specifier. Uses lower-case letters. (`lx`, `zx`, `tx`, `wf16x`, `wf32x`)
 	/// 
 	/// # Examples
 	/// 
@@ -348,7 +680,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLowerHex,
 	
-	/// Represents hexadecimal usize format specifier. Uses upper-case letters. (`lX`)
+	/// Represents hexadecimal usize format specifier. Uses upper-case letters. (`lX`, `zX`, `tX`, `wf16X`, `wf32X`)
 	/// 
 	/// # Examples
 	/// 
@@ -361,7 +693,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongUpperHex,
 	
-	/// Represents octal usize format specifier. (`lo`)
+	/// Represents octal usize format specifier. (`lo`, `zo`, `to`, `wf16o`, `wf32o`)
 	/// 
 	/// # Examples
 	/// 
@@ -374,7 +706,31 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongOctal,
 	
-	/// Represents decimal usize format specifier. (`lu`)
+	/// Represents binary usize format specifier. (`lb`, `zb`, `tb`, `wf16b`, `wf32b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%.8lb".to_string(), vec![CFormatArgument::UIntSize(5)]), "00000101");
+	/// ```
+	LongBinary,
+	
+	/// Represents binary usize format specifier. Uses upper-case prefix. (`lB`, `zB`, `tB`, `wf16B`, `wf32B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#lB".to_string(), vec![CFormatArgument::UIntSize(5)]), "0B101");
+	/// ```
+	LongUpperBinary,
+	
+	/// Represents decimal usize format specifier. (`lu`, `zu`, `tu`, `wf16u`, `wf32u`)
 	/// 
 	/// # Examples
 	/// 
@@ -387,7 +743,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongUnsigned,
 	
-	/// Represents decimal i64 format specifier. (`lli`, `lld`)
+	/// Represents decimal i64 format specifier. (`lli`, `lld`, `ji`, `w64i`, `wf64i`)
 	/// 
 	/// # Examples
 	/// 
@@ -400,7 +756,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLongInt,
 	
-	/// Represents hexadecimal u64 format specifier. Uses lower-case letters. (`llx`)
+	/// Represents hexadecimal u64 format specifier. Uses lower-case letters. (`llx`, `jx`, `w64x`, `wf64x`)
 	/// 
 	/// # Examples
 	/// 
@@ -413,7 +769,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLongLowerHex,
 	
-	/// Represents hexadecimal u64 format specifier. Uses upper-case letters. (`llX`)
+	/// Represents hexadecimal u64 format specifier. Uses upper-case letters. (`llX`, `jX`, `w64X`, `wf64X`)
 	/// 
 	/// # Examples
 	/// 
@@ -426,7 +782,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLongUpperHex,
 	
-	/// Represents octal u64 format specifier. (`llo`)
+	/// Represents octal u64 format specifier. (`llo`, `jo`, `w64o`, `wf64o`)
 	/// 
 	/// # Examples
 	/// 
@@ -438,7 +794,31 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLongOctal,
 	
-	/// Represents decimal u64 format specifier. (`llu`)
+	/// Represents binary u64 format specifier. (`llb`, `jb`, `w64b`, `wf64b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%llb".to_string(), vec![CFormatArgument::UInt64(1 << 40)]), "10000000000000000000000000000000000000000");
+	/// ```
+	LongLongBinary,
+	
+	/// Represents binary u64 format specifier. Uses upper-case prefix. (`llB`, `jB`, `w64B`, `wf64B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#jB".to_string(), vec![CFormatArgument::UInt64(1 << 40)]), "0B10000000000000000000000000000000000000000");
+	/// ```
+	LongLongUpperBinary,
+	
+	/// Represents decimal u64 format specifier. (`llu`, `ju`, `w64u`, `wf64u`)
 	/// 
 	/// # Examples
 	/// 
@@ -450,7 +830,7 @@ pub enum CFormatTypeSpecifier {
 	/// ```
 	LongLongUnsigned,
 	
-	/// Represents string format specifier. (`s`)
+	/// Represents decimal i128 format specifier. (`w128i`, `w128d`, `wf128i`, `wf128d`)
 	/// 
 	/// # Examples
 	/// 
@@ -458,14 +838,23 @@ pub enum CFormatTypeSpecifier {
 	/// use rustrings::format::CFormatArgument;
 	/// use rustrings::format::c_format;
 	/// 
-	/// assert_eq!(c_format("hello, %s!".to_string(), vec![CFormatArgument::String("world".to_string())]), "hello, world!");
+	/// assert_eq!(c_format("%w128d".to_string(), vec![CFormatArgument::Int128(i128::MIN)]), "-170141183460469231731687303715884105728");
 	/// ```
-	String,
+	WideInt,
 	
-	/// Represents pointer format specifier. (`p`)
-	Pointer,
+	/// Represents hexadecimal u128 format specifier. Uses lower-case letters. (`w128x`, `wf128x`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%w128x".to_string(), vec![CFormatArgument::UInt128(0xbadc0ffee0ddf00d_0123456789abcdef)]), "badc0ffee0ddf00d0123456789abcdef");
+	/// ```
+	WideLowerHex,
 	
-	/// Represents escape of modulo operator (`%`) specifier. (`%`)
+	/// Represents hexadecimal u128 format specifier. Uses upper-case letters. (`w128X`, `wf128X`)
 	/// 
 	/// # Examples
 	/// 
@@ -473,372 +862,2295 @@ pub enum CFormatTypeSpecifier {
 	/// use rustrings::format::CFormatArgument;
 	/// use rustrings::format::c_format;
 	/// 
-	/// assert_eq!(c_format("%d%%".to_string(), vec![CFormatArgument::Int32(42)]), "42%");
+	/// assert_eq!(c_format("%w128X".to_string(), vec![CFormatArgument::UInt128(u128::MAX)]), "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
 	/// ```
-	Escape,
+	WideUpperHex,
 	
-	/// Represents none of above specifiers.
-	None,
-}
-
-/// Formats the string. Uses C-style formatting.
-/// 
-/// # Examples
-/// 
-/// ```
-/// use rustrings::format::CFormatArgument;
-/// use rustrings::format::c_format;
-/// 
-/// assert_eq!(c_format("*%d*".to_string(),
-///     vec![
-///         CFormatArgument::Int32(959),
-///     ]
-/// ), "*959*");
-/// assert_eq!(c_format("*%2d*".to_string(),
-///     vec![
-///         CFormatArgument::Int32(959),
-///     ]
-/// ), "*959*");
-/// assert_eq!(c_format("*%10d*".to_string(),
-///     vec![
-///         CFormatArgument::Int32(959),
-///     ]
-/// ), "*       959*");
-/// assert_eq!(c_format("*%-10d*".to_string(),
-///     vec![
-///         CFormatArgument::Int32(959),
-///     ]
-/// ), "*959       *");
-/// ```
-pub fn c_format(
-	format: String,
-	arguments: Vec<CFormatArgument>,
-) -> String {
-	let mut result = String::new();
-	let mut format_iterator = format.chars();
-	let mut args_iterator = arguments.iter();
-	while let Some(mut character) = format_iterator.next() {
-		if character == '%' {
-			character = if let Some(temp) = format_iterator.next() {
-				temp
-			} else {
-				return result
-			};
-			let mut options = FormatOptions::new(FormatDirection::None, None);
-			match character {
-				'0' => {
-					character = if let Some(temp) = format_iterator.next() {
-						temp
-					} else {
-						return result
-					};
-					options.set_filling(Some('0'));
-				}
-				'*' => {
-					character = if let Some(temp) = format_iterator.next() {
-						temp
-					} else {
-						return result
-					};
-					if let CFormatArgument::IntSize(padding) = args_iterator.next().unwrap() {
-						options.set_padding(if *padding < 0 {
-							FormatDirection::Left(-*padding as usize)
-						} else {
-							FormatDirection::Right(*padding as usize)
-						});
-					} else {
-						continue;
-					}
-				},
-				'-' | '1'..='9' => {
-					let sign = if character == '-' {
-						character = if let Some(temp) = format_iterator.next() {
-							temp
-						} else {
-							return result
-						};
-						true
-					} else {
-						false
-					};
-					let mut padding = String::new();
-					loop {
-						if !character.is_digit(10) {
-							break;
-						}
-						padding.push(character);
-						character = if let Some(temp) = format_iterator.next() {
-							temp
-						} else {
-							return result
-						};
-					}
-					options.set_padding(if sign {
-						FormatDirection::Left(atoi_unsigned(&padding, Base::new(10), false).unwrap())
-					} else {
-						FormatDirection::Right(atoi_unsigned(&padding, Base::new(10), false).unwrap())
-					});
-				},
-				_ => {},
-			}
-			let type_specifier = match character {
-				'c' => CFormatTypeSpecifier::Character,
-				'd' | 'i' => CFormatTypeSpecifier::Integer,
-				'h' => {
-					character = if let Some(temp) = format_iterator.next() {
-						temp
-					} else {
-						return result
-					};
-					match character {
-						'h' => {
-							character = if let Some(temp) = format_iterator.next() {
-								temp
-							} else {
-								return result
-							};
-							match character {
-								'd' | 'i' => CFormatTypeSpecifier::ByteInt,
-								'x' => CFormatTypeSpecifier::ByteLowerHex,
-								'X' => CFormatTypeSpecifier::ByteUpperHex,
-								'u' => CFormatTypeSpecifier::ByteUnsigned,
-								'o' => CFormatTypeSpecifier::ByteOctal,
-								_ => {
-									return result
-								},
-							}
-						},
-						'd' | 'i' => CFormatTypeSpecifier::ShortInt,
-						'x' => CFormatTypeSpecifier::ShortLowerHex,
-						'X' => CFormatTypeSpecifier::ShortUpperHex,
-						'u' => CFormatTypeSpecifier::ShortUnsigned,
-						'o' => CFormatTypeSpecifier::ShortOctal,
-						_ => {
-							return result
-						},
-					}
-				},
-				'l' => {
-					character = if let Some(temp) = format_iterator.next() {
-						temp
-					} else {
-						return result
-					};
-					match character {
-						'l' => {
-							character = if let Some(temp) = format_iterator.next() {
-								temp
-							} else {
-								return result
-							};
-							match character {
-								'd' | 'i' => CFormatTypeSpecifier::LongLongInt,
-								'x' => CFormatTypeSpecifier::LongLongLowerHex,
-								'X' => CFormatTypeSpecifier::LongLongUpperHex,
-								'u' => CFormatTypeSpecifier::LongLongUnsigned,
-								'o' => CFormatTypeSpecifier::LongLongOctal,
-								_ => {
-									return result
-								},
-							}
-						},
-						'd' | 'i' => CFormatTypeSpecifier::LongInt,
-						'x' => CFormatTypeSpecifier::LongLowerHex,
-						'X' => CFormatTypeSpecifier::LongUpperHex,
-						'u' => CFormatTypeSpecifier::LongUnsigned,
-						'o' => CFormatTypeSpecifier::LongOctal,
-						_ => {
-							return result
-						},
-					}
-				},
-				'o' => CFormatTypeSpecifier::Octal,
-				'u' => CFormatTypeSpecifier::Unsigned,
-				'x' => CFormatTypeSpecifier::LowerHex,
-				'X' => CFormatTypeSpecifier::UpperHex,
-				'p' => CFormatTypeSpecifier::Pointer,
-				's' => CFormatTypeSpecifier::String,
-				'%' => CFormatTypeSpecifier::Escape,
-				_ => CFormatTypeSpecifier::None,
-			};
-			match type_specifier {
-				CFormatTypeSpecifier::Character => result.push(
-					if let CFormatArgument::Character(temp) = args_iterator.next().unwrap() {
-						*temp
-					} else {
-						return result
-					}
-				),
-				CFormatTypeSpecifier::Integer => result.push_str(&format_text(&itoa_signed(
-					if let CFormatArgument::Int32(temp) = args_iterator.next().unwrap() {
-						*temp as isize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::Octal => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt32(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(8), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::Unsigned => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt32(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LowerHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt32(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::UpperHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt32(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Upper), options).as_str()),
-				CFormatTypeSpecifier::LongInt => result.push_str(&format_text(&itoa_signed(
-					if let CFormatArgument::IntSize(temp) = args_iterator.next().unwrap() {
-						*temp as isize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongOctal => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UIntSize(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(8), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongUnsigned => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UIntSize(temp) = args_iterator.next().unwrap() {
-						*temp
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongLowerHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UIntSize(temp) = args_iterator.next().unwrap() {
-						*temp
-					} else {
-						return result
-					}, Base::new(16), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongUpperHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UIntSize(temp) = args_iterator.next().unwrap() {
-						*temp
-					} else {
-						return result
-					}, Base::new(16), Case::Upper), options).as_str()),
-				CFormatTypeSpecifier::LongLongInt => result.push_str(&format_text(&itoa_signed(
-					if let CFormatArgument::Int64(temp) = args_iterator.next().unwrap() {
-						*temp as isize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongLongOctal => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt64(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(8), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongLongUnsigned => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt64(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongLongLowerHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt64(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::LongLongUpperHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt64(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Upper), options).as_str()),
-				CFormatTypeSpecifier::ShortInt => result.push_str(&format_text(&itoa_signed(
-					if let CFormatArgument::Int16(temp) = args_iterator.next().unwrap() {
-						*temp as isize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ShortOctal => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt16(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(8), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ShortUnsigned => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt16(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ShortLowerHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt16(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ShortUpperHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt16(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Upper), options).as_str()),
-				CFormatTypeSpecifier::ByteInt => result.push_str(&format_text(&itoa_signed(
-					if let CFormatArgument::Int8(temp) = args_iterator.next().unwrap() {
-						*temp as isize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ByteOctal => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt8(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(8), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ByteUnsigned => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt8(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(10), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ByteLowerHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt8(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Lower), options).as_str()),
-				CFormatTypeSpecifier::ByteUpperHex => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::UInt8(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Upper), options).as_str()),
-				CFormatTypeSpecifier::Pointer => result.push_str(&format_text(&itoa_unsigned(
-					if let CFormatArgument::Pointer(temp) = args_iterator.next().unwrap() {
-						*temp as usize
-					} else {
-						return result
-					}, Base::new(16), Case::Upper),
-						FormatOptions::new(FormatDirection::Right(usize::BITS as usize / u8::BITS as usize), Some('0'))).as_str()
-					),
-				CFormatTypeSpecifier::String => result.push_str(&format_text(&(
-					if let CFormatArgument::String(temp) = args_iterator.next().unwrap() {
-						temp.to_string()
-					} else {
-						return result
-					}), options)),
-				CFormatTypeSpecifier::Escape => result.push('%'),
-				_ => {},
-			}
-			continue;
-		}
-		result.push(character);
-	}
-	result
-}
+	/// Represents octal u128 format specifier. (`w128o`, `wf128o`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%w128o".to_string(), vec![CFormatArgument::UInt128(1 << 100)]), "2000000000000000000000000000000000");
+	/// ```
+	WideOctal,
+	
+	/// Represents binary u128 format specifier. (`w128b`, `wf128b`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%w128b".to_string(), vec![CFormatArgument::UInt128(u128::MAX)]), "11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111");
+	/// ```
+	WideBinary,
+	
+	/// Represents binary u128 format specifier. Uses upper-case prefix. (`w128B`, `wf128B`, C23)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%#wf128B".to_string(), vec![CFormatArgument::UInt128(u128::MAX)]), "0B11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111");
+	/// ```
+	WideUpperBinary,
+	
+	/// Represents decimal u128 format specifier. (`w128u`, `wf128u`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%w128u".to_string(), vec![CFormatArgument::UInt128(u128::MAX)]), "340282366920938463463374607431768211455");
+	/// ```
+	WideUnsigned,
+	
+	/// Represents custom conversion registered in `CFormatRegistry`, with conversion character or name (`%{name}`).
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::CFormatCustom;
+	/// use rustrings::format::CFormatRegistry;
+	/// use rustrings::format::CFormatSpec;
+	/// use rustrings::format::c_format_with;
+	/// 
+	/// let mut registry = CFormatRegistry::new();
+	/// registry.register_named("hex", |_: &CFormatSpec, _, _, value: &dyn CFormatCustom| {
+	///     value.as_any().downcast_ref::<Vec<u8>>().map_or(String::new(), |e| e.iter().map(|e| format!("{:02x}", e)).collect())
+	/// });
+	/// assert_eq!(c_format_with("%{hex}".to_string(), vec![CFormatArgument::Custom(Box::new(vec![1u8, 171]))], &registry), "01ab");
+	/// ```
+	Custom,
+	
+	/// Represents decimal floating point format specifier. (`f`, `lf`, `Lf`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%.2f".to_string(), vec![CFormatArgument::Float64(3.14159)]), "3.14");
+	/// ```
+	Float,
+	
+	/// Represents decimal floating point format specifier. Uses upper-case letters for infinity and NaN. (`F`, `lF`, `LF`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%F".to_string(), vec![CFormatArgument::Float64(f64::INFINITY)]), "INF");
+	/// ```
+	UpperFloat,
+	
+	/// Represents scientific floating point format specifier. Uses lower-case letters. (`e`, `le`, `Le`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%.3e".to_string(), vec![CFormatArgument::Float64(123456.0)]), "1.235e+05");
+	/// ```
+	Exponent,
+	
+	/// Represents scientific floating point format specifier. Uses upper-case letters. (`E`, `lE`, `LE`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%.3E".to_string(), vec![CFormatArgument::Float64(0.000123456)]), "1.235E-04");
+	/// ```
+	UpperExponent,
+	
+	/// Represents shortest of decimal and scientific floating point format specifier. Uses lower-case letters. (`g`, `lg`, `Lg`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%g".to_string(), vec![CFormatArgument::Float64(0.0001)]), "0.0001");
+	/// ```
+	General,
+	
+	/// Represents shortest of decimal and scientific floating point format specifier. Uses upper-case letters. (`G`, `lG`, `LG`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%G".to_string(), vec![CFormatArgument::Float64(1e-5)]), "1E-05");
+	/// ```
+	UpperGeneral,
+	
+	/// Represents hexadecimal floating point format specifier. Uses lower-case letters. (`a`, `la`, `La`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%a".to_string(), vec![CFormatArgument::Float64(1.5)]), "0x1.8p+0");
+	/// ```
+	HexFloat,
+	
+	/// Represents hexadecimal floating point format specifier. Uses upper-case letters. (`A`, `lA`, `LA`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%A".to_string(), vec![CFormatArgument::Float64(-0.125)]), "-0X1P-3");
+	/// ```
+	UpperHexFloat,
+	
+	/// Represents string format specifier. (`s`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("hello, %s!".to_string(), vec![CFormatArgument::String("world".to_string())]), "hello, world!");
+	/// ```
+	String,
+	
+	/// Represents pointer format specifier. (`p`)
+	Pointer,
+	
+	/// Represents escape of modulo operator (`%`) specifier. (`%`)
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%d%%".to_string(), vec![CFormatArgument::Int32(42)]), "42%");
+	/// ```
+	Escape,
+	
+	/// Represents none of above specifiers.
+	None,
+}
+
+/// Flags of conversion specification.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CFormatFlags {
+	/// Left-justify within field (`-`).
+	pub left: bool,
+	
+	/// Pad numbers with zeros after sign and prefix instead of spaces (`0`).
+	pub zero: bool,
+	
+	/// Write plus sign for non-negative signed numbers (`+`).
+	pub plus: bool,
+	
+	/// Write space for non-negative signed numbers, if `+` is not set (` `).
+	pub space: bool,
+	
+	/// Alternate form: `0` prefix for octal, `0x`/`0X` prefix for non-zero hexadecimal,
+	/// `0b`/`0B` prefix for non-zero binary (`#`).
+	pub alternate: bool,
+	
+	/// Group thousands of decimal conversions with commas (`'`, POSIX),
+	/// and nibbles of binary conversions with underscores (extension).
+	pub grouping: bool,
+	
+	/// Append ordinal suffix to decimal conversions (`~`, extension). Suffix is English,
+	/// unless other locale is chosen with `c_format_localized` or `CFormat::set_locale`.
+	pub ordinal: bool,
+}
+
+/// Field width or precision of conversion specification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CFormatCount {
+	/// Written in format string.
+	Fixed(usize),
+	
+	/// Taken from next argument (`*`).
+	Argument,
+	
+	/// Taken from argument at position, starting from 1 (`*m$`).
+	Position(usize),
+}
+
+/// Length modifier of conversion specification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CFormatLength {
+	/// No length modifier.
+	None,
+	
+	/// `hh`.
+	Char,
+	
+	/// `h`.
+	Short,
+	
+	/// `l`.
+	Long,
+	
+	/// `ll`.
+	LongLong,
+	
+	/// `z`, size of object: `CFormatArgument::UIntSize` or `CFormatArgument::IntSize`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%zu".to_string(), vec![CFormatArgument::UIntSize(usize::MAX)]), usize::MAX.to_string());
+	/// assert_eq!(c_format("%zd".to_string(), vec![CFormatArgument::IntSize(-42)]), "-42");
+	/// ```
+	Size,
+	
+	/// `j`, greatest integer: `CFormatArgument::Int64` or `CFormatArgument::UInt64`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%jd".to_string(), vec![CFormatArgument::Int64(i64::MIN)]), "-9223372036854775808");
+	/// assert_eq!(c_format("%jX".to_string(), vec![CFormatArgument::UInt64(13464654573299691533u64)]), "BADC0FFEE0DDF00D");
+	/// ```
+	Max,
+	
+	/// `t`, difference of pointers: `CFormatArgument::IntSize` or `CFormatArgument::UIntSize`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%td".to_string(), vec![CFormatArgument::IntSize(-42)]), "-42");
+	/// assert_eq!(c_format("%tx".to_string(), vec![CFormatArgument::UIntSize(42)]), "2a");
+	/// ```
+	PtrDiff,
+	
+	/// `L`, long double. Rust has no such type, so floating point conversions take `CFormatArgument::Float64`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%.3Lf".to_string(), vec![CFormatArgument::Float64(2.5)]), "2.500");
+	/// ```
+	LongDouble,
+	
+	/// `wN` (C23), integer of exactly N bits. N is 8, 16, 32, 64 or 128 (extension).
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%w8x".to_string(), vec![CFormatArgument::UInt8(42)]), "2a");
+	/// assert_eq!(c_format("%w32d".to_string(), vec![CFormatArgument::Int32(-42)]), "-42");
+	/// assert_eq!(c_format("%w64u".to_string(), vec![CFormatArgument::UInt64(u64::MAX)]), "18446744073709551615");
+	/// ```
+	Width(u32),
+	
+	/// `wfN` (C23), fastest integer of at least N bits. N is 8, 16, 32, 64 or 128 (extension).
+	/// Like in glibc, 8-bit integers are `CFormatArgument::Int8` and `CFormatArgument::UInt8`,
+	/// 16-bit and 32-bit integers are `CFormatArgument::IntSize` and `CFormatArgument::UIntSize`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::c_format;
+	/// 
+	/// assert_eq!(c_format("%wf8d".to_string(), vec![CFormatArgument::Int8(-42)]), "-42");
+	/// assert_eq!(c_format("%wf32u".to_string(), vec![CFormatArgument::UIntSize(42)]), "42");
+	/// assert_eq!(c_format("%wf64X".to_string(), vec![CFormatArgument::UInt64(13464654573299691533u64)]), "BADC0FFEE0DDF00D");
+	/// ```
+	FastWidth(u32),
+}
+
+/// Parsed conversion specification, `%[argument$][flags][width][.precision][length]conversion`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CFormatSpec {
+	/// Position of converted argument, starting from 1 (`n$`).
+	pub argument: Option<usize>,
+	
+	/// Flags.
+	pub flags: CFormatFlags,
+	
+	/// Minimum field width.
+	pub width: Option<CFormatCount>,
+	
+	/// Precision.
+	pub precision: Option<CFormatCount>,
+	
+	/// Length modifier.
+	pub length: CFormatLength,
+	
+	/// Conversion character.
+	pub conversion: char,
+	
+	/// Type specifier, combined from length modifier and conversion.
+	pub specifier: CFormatTypeSpecifier,
+	
+	/// Name of custom conversion `%{name}`, whose conversion character is `{`.
+	pub name: Option<String>,
+}
+
+impl CFormatSpec {
+	/// Returns variant of argument converted by specification,
+	/// or `None` if it doesn't convert argument (`%%` and unknown conversions).
+	/// Width and precision taken from arguments are always `CFormatArgumentKind::IntSize`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatArgumentKind;
+	/// use rustrings::format::CFormatParser;
+	/// use rustrings::format::CFormatSegment;
+	/// 
+	/// let kinds: Vec<Option<CFormatArgumentKind>> = CFormatParser::new("%s: %*lld%%")
+	///     .filter_map(|e| match e.unwrap() {
+	///         CFormatSegment::Conversion { spec, .. } => Some(spec.argument_kind()),
+	///         CFormatSegment::Literal { .. } => None,
+	///     })
+	///     .collect();
+	/// assert_eq!(kinds, [Some(CFormatArgumentKind::String), Some(CFormatArgumentKind::Int64), None]);
+	/// ```
+	pub fn argument_kind(&self) -> Option<CFormatArgumentKind> {
+		specifier_kind(&self.specifier, self.length, self.conversion)
+	}
+	
+	/// Writes specification back to format string. Flags are written in order `-+ #0'~`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::format::CFormatParser;
+	/// use rustrings::format::CFormatSegment;
+	/// 
+	/// match CFormatParser::new("%2$0-*1$.3hhx").next() {
+	///     Some(Ok(CFormatSegment::Conversion { spec, .. })) => assert_eq!(spec.to_format(), "%2$-0*1$.3hhx"),
+	///     _ => unreachable!(),
+	/// }
+	/// ```
+	pub fn to_format(&self) -> String {
+		let mut result = String::from('%');
+		if let Some(argument) = self.argument {
+			result.push_str(&format!("{}$", argument));
+		}
+		for (flag, character) in [
+			(self.flags.left, '-'),
+			(self.flags.plus, '+'),
+			(self.flags.space, ' '),
+			(self.flags.alternate, '#'),
+			(self.flags.zero, '0'),
+			(self.flags.grouping, '\''),
+			(self.flags.ordinal, '~'),
+		] {
+			if flag {
+				result.push(character);
+			}
+		}
+		let count = |count: &CFormatCount| match count {
+			CFormatCount::Fixed(value) => value.to_string(),
+			CFormatCount::Argument => "*".to_string(),
+			CFormatCount::Position(position) => format!("*{}$", position),
+		};
+		if let Some(width) = &self.width {
+			result.push_str(&count(width));
+		}
+		if let Some(precision) = &self.precision {
+			result.push('.');
+			result.push_str(&count(precision));
+		}
+		result.push_str(match self.length {
+			CFormatLength::None => "",
+			CFormatLength::Char => "hh",
+			CFormatLength::Short => "h",
+			CFormatLength::Long => "l",
+			CFormatLength::LongLong => "ll",
+			CFormatLength::Size => "z",
+			CFormatLength::Max => "j",
+			CFormatLength::PtrDiff => "t",
+			CFormatLength::LongDouble => "L",
+			CFormatLength::Width(_) | CFormatLength::FastWidth(_) => "",
+		});
+		match self.length {
+			CFormatLength::Width(bits) => result.push_str(&format!("w{}", bits)),
+			CFormatLength::FastWidth(bits) => result.push_str(&format!("wf{}", bits)),
+			_ => {},
+		}
+		match &self.name {
+			Some(name) => result.push_str(&format!("{{{}}}", name)),
+			None => result.push(self.conversion),
+		}
+		result
+	}
+}
+
+/// Returns variant of argument converted by type specifier, or `None` if it doesn't convert argument.
+const fn specifier_kind(
+	specifier: &CFormatTypeSpecifier,
+	length: CFormatLength,
+	conversion: char,
+) -> Option<CFormatArgumentKind> {
+	Some(match specifier {
+		CFormatTypeSpecifier::Character => CFormatArgumentKind::Character,
+		CFormatTypeSpecifier::String => CFormatArgumentKind::String,
+		CFormatTypeSpecifier::Pointer => CFormatArgumentKind::Pointer,
+		CFormatTypeSpecifier::Escape | CFormatTypeSpecifier::None => return None,
+		CFormatTypeSpecifier::Custom => CFormatArgumentKind::Custom,
+		CFormatTypeSpecifier::Float
+		| CFormatTypeSpecifier::UpperFloat
+		| CFormatTypeSpecifier::Exponent
+		| CFormatTypeSpecifier::UpperExponent
+		| CFormatTypeSpecifier::General
+		| CFormatTypeSpecifier::UpperGeneral
+		| CFormatTypeSpecifier::HexFloat
+		| CFormatTypeSpecifier::UpperHexFloat => CFormatArgumentKind::Float64,
+		_ => return integer_kind(length, conversion == 'd' || conversion == 'i'),
+	})
+}
+
+/// Returns type specifier for length modifier and conversion character,
+/// or `None` if conversion doesn't accept length modifier.
+const fn type_specifier(
+	length: CFormatLength,
+	conversion: char,
+) -> Option<CFormatTypeSpecifier> {
+	let float = matches!(length, CFormatLength::None | CFormatLength::Long | CFormatLength::LongDouble);
+	Some(match (length, conversion) {
+		(CFormatLength::None, 'c') => CFormatTypeSpecifier::Character,
+		(CFormatLength::None, 'p') => CFormatTypeSpecifier::Pointer,
+		(CFormatLength::None, 's') => CFormatTypeSpecifier::String,
+		(CFormatLength::None, '%') => CFormatTypeSpecifier::Escape,
+		(_, 'f') if float => CFormatTypeSpecifier::Float,
+		(_, 'F') if float => CFormatTypeSpecifier::UpperFloat,
+		(_, 'e') if float => CFormatTypeSpecifier::Exponent,
+		(_, 'E') if float => CFormatTypeSpecifier::UpperExponent,
+		(_, 'g') if float => CFormatTypeSpecifier::General,
+		(_, 'G') if float => CFormatTypeSpecifier::UpperGeneral,
+		(_, 'a') if float => CFormatTypeSpecifier::HexFloat,
+		(_, 'A') if float => CFormatTypeSpecifier::UpperHexFloat,
+		(_, 'd' | 'i' | 'o' | 'u' | 'x' | 'X' | 'b' | 'B') => {
+			/* integer conversions are chosen by argument of length modifier */
+			let Some(kind) = integer_kind(length, true) else {
+				return None;
+			};
+			match (kind, conversion) {
+				(CFormatArgumentKind::Int32, 'd' | 'i') => CFormatTypeSpecifier::Integer,
+				(CFormatArgumentKind::Int32, 'o') => CFormatTypeSpecifier::Octal,
+				(CFormatArgumentKind::Int32, 'u') => CFormatTypeSpecifier::Unsigned,
+				(CFormatArgumentKind::Int32, 'x') => CFormatTypeSpecifier::LowerHex,
+				(CFormatArgumentKind::Int32, 'b') => CFormatTypeSpecifier::Binary,
+				(CFormatArgumentKind::Int32, 'B') => CFormatTypeSpecifier::UpperBinary,
+				(CFormatArgumentKind::Int32, _) => CFormatTypeSpecifier::UpperHex,
+				(CFormatArgumentKind::Int8, 'd' | 'i') => CFormatTypeSpecifier::ByteInt,
+				(CFormatArgumentKind::Int8, 'o') => CFormatTypeSpecifier::ByteOctal,
+				(CFormatArgumentKind::Int8, 'u') => CFormatTypeSpecifier::ByteUnsigned,
+				(CFormatArgumentKind::Int8, 'x') => CFormatTypeSpecifier::ByteLowerHex,
+				(CFormatArgumentKind::Int8, 'b') => CFormatTypeSpecifier::ByteBinary,
+				(CFormatArgumentKind::Int8, 'B') => CFormatTypeSpecifier::ByteUpperBinary,
+				(CFormatArgumentKind::Int8, _) => CFormatTypeSpecifier::ByteUpperHex,
+				(CFormatArgumentKind::Int16, 'd' | 'i') => CFormatTypeSpecifier::ShortInt,
+				(CFormatArgumentKind::Int16, 'o') => CFormatTypeSpecifier::ShortOctal,
+				(CFormatArgumentKind::Int16, 'u') => CFormatTypeSpecifier::ShortUnsigned,
+				(CFormatArgumentKind::Int16, 'x') => CFormatTypeSpecifier::ShortLowerHex,
+				(CFormatArgumentKind::Int16, 'b') => CFormatTypeSpecifier::ShortBinary,
+				(CFormatArgumentKind::Int16, 'B') => CFormatTypeSpecifier::ShortUpperBinary,
+				(CFormatArgumentKind::Int16, _) => CFormatTypeSpecifier::ShortUpperHex,
+				(CFormatArgumentKind::IntSize, 'd' | 'i') => CFormatTypeSpecifier::LongInt,
+				(CFormatArgumentKind::IntSize, 'o') => CFormatTypeSpecifier::LongOctal,
+				(CFormatArgumentKind::IntSize, 'u') => CFormatTypeSpecifier::LongUnsigned,
+				(CFormatArgumentKind::IntSize, 'x') => CFormatTypeSpecifier::LongLowerHex,
+				(CFormatArgumentKind::IntSize, 'b') => CFormatTypeSpecifier::LongBinary,
+				(CFormatArgumentKind::IntSize, 'B') => CFormatTypeSpecifier::LongUpperBinary,
+				(CFormatArgumentKind::IntSize, _) => CFormatTypeSpecifier::LongUpperHex,
+				(CFormatArgumentKind::Int64, 'd' | 'i') => CFormatTypeSpecifier::LongLongInt,
+				(CFormatArgumentKind::Int64, 'o') => CFormatTypeSpecifier::LongLongOctal,
+				(CFormatArgumentKind::Int64, 'u') => CFormatTypeSpecifier::LongLongUnsigned,
+				(CFormatArgumentKind::Int64, 'x') => CFormatTypeSpecifier::LongLongLowerHex,
+				(CFormatArgumentKind::Int64, 'b') => CFormatTypeSpecifier::LongLongBinary,
+				(CFormatArgumentKind::Int64, 'B') => CFormatTypeSpecifier::LongLongUpperBinary,
+				(CFormatArgumentKind::Int64, _) => CFormatTypeSpecifier::LongLongUpperHex,
+				(_, 'd' | 'i') => CFormatTypeSpecifier::WideInt,
+				(_, 'o') => CFormatTypeSpecifier::WideOctal,
+				(_, 'u') => CFormatTypeSpecifier::WideUnsigned,
+				(_, 'x') => CFormatTypeSpecifier::WideLowerHex,
+				(_, 'b') => CFormatTypeSpecifier::WideBinary,
+				(_, 'B') => CFormatTypeSpecifier::WideUpperBinary,
+				(_, _) => CFormatTypeSpecifier::WideUpperHex,
+			}
+		},
+		(CFormatLength::None, _) => CFormatTypeSpecifier::None,
+		_ => return None,
+	})
+}
+
+/// Parses argument position `n$`, leaving iterator untouched if there is none.
+fn parse_position(iterator: &mut Peekable<CharIndices>) -> Option<usize> {
+	let mut lookahead = iterator.clone();
+	let mut digits = String::new();
+	while let Some((_, digit)) = lookahead.next_if(|e| e.1.is_ascii_digit()) {
+		digits.push(digit);
+	}
+	let position = atoi_unsigned(&digits, Base::new(10), false)
+		.ok()
+		.filter(|e| *e != 0)?;
+	lookahead.next_if(|e| e.1 == '$')?;
+	*iterator = lookahead;
+	Some(position)
+}
+
+/// Parses width or precision: `*`, `*m$` or decimal number.
+fn parse_count(iterator: &mut Peekable<CharIndices>) -> CFormatCount {
+	if iterator.next_if(|e| e.1 == '*').is_some() {
+		return match parse_position(iterator) {
+			Some(position) => CFormatCount::Position(position),
+			None => CFormatCount::Argument,
+		};
+	}
+	let mut digits = String::new();
+	while let Some((_, digit)) = iterator.next_if(|e| e.1.is_ascii_digit()) {
+		digits.push(digit);
+	}
+	/* empty precision (`%.d`) means zero */
+	CFormatCount::Fixed(atoi_unsigned(&digits, Base::new(10), false).unwrap_or(0))
+}
+
+/// Parses conversion specification after `%`.
+/// Returns `Err(true)` if it is incomplete, or `Err(false)` if conversion doesn't accept length modifier.
+fn parse_spec(iterator: &mut Peekable<CharIndices>) -> Result<CFormatSpec, bool> {
+	let argument = parse_position(iterator);
+	let mut flags = CFormatFlags::default();
+	loop {
+		match iterator.peek().ok_or(true)?.1 {
+			'-' => flags.left = true,
+			'0' => flags.zero = true,
+			'+' => flags.plus = true,
+			' ' => flags.space = true,
+			'#' => flags.alternate = true,
+			'\'' => flags.grouping = true,
+			/* extension: `~` appends ordinal suffix of locale to decimal conversions */
+			'~' => flags.ordinal = true,
+			_ => break,
+		}
+		iterator.next();
+	}
+	let width = match iterator.peek().ok_or(true)?.1 {
+		'*' | '1'..='9' => Some(parse_count(iterator)),
+		_ => None,
+	};
+	let precision = if iterator.next_if(|e| e.1 == '.').is_some() {
+		Some(parse_count(iterator))
+	} else {
+		None
+	};
+	let length = match iterator.next_if(|e| "hlzjtLw".contains(e.1)).map(|e| e.1) {
+		Some('h') if iterator.next_if(|e| e.1 == 'h').is_some() => CFormatLength::Char,
+		Some('h') => CFormatLength::Short,
+		Some('l') if iterator.next_if(|e| e.1 == 'l').is_some() => CFormatLength::LongLong,
+		Some('l') => CFormatLength::Long,
+		Some('z') => CFormatLength::Size,
+		Some('j') => CFormatLength::Max,
+		Some('t') => CFormatLength::PtrDiff,
+		Some('L') => CFormatLength::LongDouble,
+		Some(_) => {
+			/* number of bits is checked with conversion */
+			let fast = iterator.next_if(|e| e.1 == 'f').is_some();
+			let mut digits = String::new();
+			while let Some((_, digit)) = iterator.next_if(|e| e.1.is_ascii_digit()) {
+				digits.push(digit);
+			}
+			let bits = atoi_unsigned(&digits, Base::new(10), false)
+				.ok()
+				.and_then(|e| u32::try_from(e).ok())
+				.unwrap_or(0);
+			if fast {
+				CFormatLength::FastWidth(bits)
+			} else {
+				CFormatLength::Width(bits)
+			}
+		},
+		None => CFormatLength::None,
+	};
+	let conversion = iterator.next().ok_or(true)?.1;
+	let specifier = type_specifier(length, conversion).ok_or(false)?;
+	if conversion != '{' {
+		return Ok(CFormatSpec {
+			argument,
+			flags,
+			width,
+			precision,
+			length,
+			conversion,
+			specifier,
+			name: None,
+		});
+	}
+	/* custom conversion `%{name}` */
+	let mut name = String::new();
+	loop {
+		match iterator.next().ok_or(true)?.1 {
+			'}' => break,
+			character => name.push(character),
+		}
+	}
+	if name.is_empty() {
+		return Err(false);
+	}
+	Ok(CFormatSpec {
+		argument,
+		flags,
+		width,
+		precision,
+		length,
+		conversion,
+		specifier: CFormatTypeSpecifier::Custom,
+		name: Some(name),
+	})
+}
+
+/// Reason of failed conversion, completed to `FormatError` with position of specification.
+enum SpecError {
+	/// Argument with index is not supplied.
+	MissingArgument(usize),
+	
+	/// Argument with index doesn't match expected variant.
+	Mismatch(usize, CFormatArgumentKind, CFormatArgumentKind),
+}
+
+/// Arguments of `c_format`, taken in order or by position.
+struct CFormatArguments<'a> {
+	/// Supplied arguments.
+	arguments: &'a [CFormatArgument],
+	
+	/// Index of next argument taken in order.
+	next: usize,
+	
+	/// Number of arguments up to the last taken one.
+	used: usize,
+	
+	/// Whether argument with index was taken.
+	taken: Vec<bool>,
+	
+	/// Whether arguments are coerced like in C.
+	coerce: bool,
+}
+
+impl<'a> CFormatArguments<'a> {
+	/// Constructs CFormatArguments.
+	fn new(
+		arguments: &'a [CFormatArgument],
+		coerce: bool,
+	) -> CFormatArguments<'a> {
+		CFormatArguments {
+			arguments,
+			next: 0,
+			used: 0,
+			taken: vec![false; arguments.len()],
+			coerce,
+		}
+	}
+	
+	/// Takes argument at position, starting from 1, or next argument, if it is expected variant.
+	/// `Float64` also accepts `Float32`. In coercion mode, integer, character and pointer arguments
+	/// are accepted by each other's conversions.
+	fn take(
+		&mut self,
+		position: Option<usize>,
+		expected: CFormatArgumentKind,
+	) -> Result<&'a CFormatArgument, SpecError> {
+		let index = match position {
+			Some(position) => position - 1,
+			None => {
+				self.next += 1;
+				self.next - 1
+			},
+		};
+		let argument = self
+			.arguments
+			.get(index)
+			.ok_or(SpecError::MissingArgument(index))?;
+		self.used = self.used.max(index + 1);
+		self.taken[index] = true;
+		let supplied = argument.kind();
+		let integer = |kind: CFormatArgumentKind| !matches!(kind,
+			CFormatArgumentKind::String | CFormatArgumentKind::Float32 | CFormatArgumentKind::Float64 | CFormatArgumentKind::Custom);
+		if supplied == expected
+			|| (expected == CFormatArgumentKind::Float64 && supplied == CFormatArgumentKind::Float32)
+			|| (self.coerce && integer(expected) && integer(supplied)) {
+			Ok(argument)
+		} else {
+			Err(SpecError::Mismatch(index, expected, supplied))
+		}
+	}
+}
+
+/// Returns argument variant expected by integer conversion with length modifier and signedness,
+/// or `None` if integer conversions don't accept length modifier.
+const fn integer_kind(
+	length: CFormatLength,
+	signed: bool,
+) -> Option<CFormatArgumentKind> {
+	let bits = match length {
+		CFormatLength::Char | CFormatLength::Width(8) | CFormatLength::FastWidth(8) => 8,
+		CFormatLength::Short | CFormatLength::Width(16) => 16,
+		CFormatLength::None | CFormatLength::Width(32) => 32,
+		CFormatLength::LongLong | CFormatLength::Max | CFormatLength::Width(64) | CFormatLength::FastWidth(64) => 64,
+		CFormatLength::Width(128) | CFormatLength::FastWidth(128) => 128,
+		/* machine word */
+		CFormatLength::Long | CFormatLength::Size | CFormatLength::PtrDiff | CFormatLength::FastWidth(16 | 32) => 0,
+		_ => return None,
+	};
+	Some(match (bits, signed) {
+		(8, true) => CFormatArgumentKind::Int8,
+		(16, true) => CFormatArgumentKind::Int16,
+		(32, true) => CFormatArgumentKind::Int32,
+		(64, true) => CFormatArgumentKind::Int64,
+		(128, true) => CFormatArgumentKind::Int128,
+		(_, true) => CFormatArgumentKind::IntSize,
+		(8, false) => CFormatArgumentKind::UInt8,
+		(16, false) => CFormatArgumentKind::UInt16,
+		(32, false) => CFormatArgumentKind::UInt32,
+		(64, false) => CFormatArgumentKind::UInt64,
+		(128, false) => CFormatArgumentKind::UInt128,
+		(_, false) => CFormatArgumentKind::UIntSize,
+	})
+}
+
+/// Returns value of integer argument as 128-bit two's complement number.
+/// Characters are code points and pointers are addresses.
+fn integer_value(argument: &CFormatArgument) -> u128 {
+	match argument {
+		CFormatArgument::Int8(value) => *value as u128,
+		CFormatArgument::Int16(value) => *value as u128,
+		CFormatArgument::Int32(value) => *value as u128,
+		CFormatArgument::Int64(value) => *value as u128,
+		CFormatArgument::Int128(value) => *value as u128,
+		CFormatArgument::IntSize(value) => *value as u128,
+		CFormatArgument::UInt8(value) => *value as u128,
+		CFormatArgument::UInt16(value) => *value as u128,
+		CFormatArgument::UInt32(value) => *value as u128,
+		CFormatArgument::UInt64(value) => *value as u128,
+		CFormatArgument::UInt128(value) => *value,
+		CFormatArgument::UIntSize(value) => *value as u128,
+		CFormatArgument::Character(value) => *value as u128,
+		CFormatArgument::Pointer(value) => *value as usize as u128,
+		_ => 0,
+	}
+}
+
+/// Converts integer to argument variant of integer conversion, like C cast, returning `(negative, magnitude)`.
+fn integer_cast(
+	value: u128,
+	kind: CFormatArgumentKind,
+) -> (bool, u128) {
+	let (bits, signed) = match kind {
+		CFormatArgumentKind::Int8 => (8, true),
+		CFormatArgumentKind::Int16 => (16, true),
+		CFormatArgumentKind::Int32 => (32, true),
+		CFormatArgumentKind::Int64 => (64, true),
+		CFormatArgumentKind::Int128 => (128, true),
+		CFormatArgumentKind::IntSize => (usize::BITS, true),
+		CFormatArgumentKind::UInt8 => (8, false),
+		CFormatArgumentKind::UInt16 => (16, false),
+		CFormatArgumentKind::UInt32 => (32, false),
+		CFormatArgumentKind::UInt64 => (64, false),
+		CFormatArgumentKind::UInt128 => (128, false),
+		_ => (usize::BITS, false),
+	};
+	let mask = u128::MAX >> (128 - bits);
+	let value = value & mask;
+	if signed && value >> (bits - 1) != 0 {
+		(true, value.wrapping_neg() & mask)
+	} else {
+		(false, value)
+	}
+}
+
+/// Resolves width or precision, taking `*` and `*m$` from `CFormatArgument::IntSize` argument,
+/// or any integer argument in coercion mode.
+fn resolve_count(
+	count: &Option<CFormatCount>,
+	arguments: &mut CFormatArguments,
+) -> Result<Option<isize>, SpecError> {
+	let position = match count {
+		Some(CFormatCount::Fixed(value)) => return Ok(Some(*value as isize)),
+		Some(CFormatCount::Argument) => None,
+		Some(CFormatCount::Position(position)) => Some(*position),
+		None => return Ok(None),
+	};
+	Ok(Some(integer_value(arguments.take(position, CFormatArgumentKind::IntSize)?) as isize))
+}
+
+/// Formats one conversion, consuming its arguments.
+fn format_spec(
+	spec: &CFormatSpec,
+	arguments: &mut CFormatArguments,
+	registry: Option<&CFormatRegistry>,
+	locale: Locale,
+) -> Result<String, SpecError> {
+	let mut left = spec.flags.left;
+	let width = resolve_count(&spec.width, arguments)?.map(|e| {
+		/* negative width taken from argument means left justification */
+		left |= e < 0;
+		e.unsigned_abs()
+	});
+	/* negative precision taken from argument is ignored */
+	let precision = resolve_count(&spec.precision, arguments)?.and_then(|e| usize::try_from(e).ok());
+	let text = match spec.specifier {
+		CFormatTypeSpecifier::Character => {
+			/* integer is code point, invalid code points are replaced */
+			let value = integer_value(arguments.take(spec.argument, CFormatArgumentKind::Character)?);
+			u32::try_from(value)
+				.ok()
+				.and_then(char::from_u32)
+				.unwrap_or(char::REPLACEMENT_CHARACTER)
+				.to_string()
+		},
+		CFormatTypeSpecifier::String => match arguments.take(spec.argument, CFormatArgumentKind::String)? {
+			CFormatArgument::String(value) => match precision {
+				Some(precision) => value
+					.chars()
+					.take(precision)
+					.collect(),
+				None => value.to_string(),
+			},
+			_ => String::new(),
+		},
+		CFormatTypeSpecifier::Pointer => {
+			let value = integer_value(arguments.take(spec.argument, CFormatArgumentKind::Pointer)?) as usize;
+			return Ok(format_text(&itoa_unsigned(value, Base::new(16), Case::Upper),
+				FormatOptions::new(FormatDirection::Right(usize::BITS as usize / u8::BITS as usize), Some('0'))));
+		},
+		CFormatTypeSpecifier::Escape => return Ok("%".to_string()),
+		CFormatTypeSpecifier::None => return Ok(String::new()),
+		CFormatTypeSpecifier::Custom => {
+			/* like unknown conversion, conversion without handler doesn't take argument */
+			let Some(handler) = registry.and_then(|e| e.handler(spec)) else {
+				return Ok(String::new());
+			};
+			match arguments.take(spec.argument, CFormatArgumentKind::Custom)? {
+				CFormatArgument::Custom(value) => handler.format(spec, width, precision, value.as_ref()),
+				_ => String::new(),
+			}
+		},
+		CFormatTypeSpecifier::Float
+		| CFormatTypeSpecifier::UpperFloat
+		| CFormatTypeSpecifier::Exponent
+		| CFormatTypeSpecifier::UpperExponent
+		| CFormatTypeSpecifier::General
+		| CFormatTypeSpecifier::UpperGeneral
+		| CFormatTypeSpecifier::HexFloat
+		| CFormatTypeSpecifier::UpperHexFloat => {
+			let value = match arguments.take(spec.argument, CFormatArgumentKind::Float64)? {
+				CFormatArgument::Float32(value) => *value as f64,
+				CFormatArgument::Float64(value) => *value,
+				_ => 0.0,
+			};
+			let sign = if value.is_sign_negative() {
+				"-"
+			} else if spec.flags.plus {
+				"+"
+			} else if spec.flags.space {
+				" "
+			} else {
+				""
+			};
+			let (prefix, digits) = float_text(value.abs(), spec.conversion, precision, &spec.flags);
+			/* infinity and NaN are never padded with zeros */
+			let zero = spec.flags.zero && !left && value.is_finite();
+			return Ok(pad_number(&format!("{}{}", sign, prefix), &digits, "", width, left, zero));
+		},
+		_ => {
+			let (base, case) = match spec.conversion {
+				'o' => (Base::new(8), Case::Lower),
+				'x' => (Base::new(16), Case::Lower),
+				'X' => (Base::new(16), Case::Upper),
+				'b' | 'B' => (Base::new(2), Case::Lower),
+				_ => (Base::new(10), Case::Lower),
+			};
+			let signed = spec.conversion == 'd' || spec.conversion == 'i';
+			let kind = integer_kind(spec.length, signed).unwrap_or(CFormatArgumentKind::Int32);
+			let (negative, magnitude) = integer_cast(integer_value(arguments.take(spec.argument, kind)?), kind);
+			/* precision is minimum number of digits */
+			let mut digits = match usize::try_from(magnitude) {
+				Ok(0) if precision == Some(0) => String::new(),
+				Ok(magnitude) => itoa_unsigned(magnitude, base, case),
+				Err(_) => match spec.conversion {
+					'o' => format!("{:o}", magnitude),
+					'x' => format!("{:x}", magnitude),
+					'X' => format!("{:X}", magnitude),
+					'b' | 'B' => format!("{:b}", magnitude),
+					_ => magnitude.to_string(),
+				},
+			};
+			if let Some(precision) = precision {
+				let length = digits.len();
+				digits.insert_str(0, &"0".repeat(precision.saturating_sub(length)));
+			}
+			let prefix = match spec.conversion {
+				/* alternate octal form increases precision, so first digit is zero */
+				'o' if spec.flags.alternate && !digits.starts_with('0') => "0",
+				'x' if spec.flags.alternate && magnitude != 0 => "0x",
+				'X' if spec.flags.alternate && magnitude != 0 => "0X",
+				'b' if spec.flags.alternate && magnitude != 0 => "0b",
+				'B' if spec.flags.alternate && magnitude != 0 => "0B",
+				_ => "",
+			};
+			let sign = if negative {
+				"-"
+			} else if signed && spec.flags.plus {
+				"+"
+			} else if signed && spec.flags.space {
+				" "
+			} else {
+				""
+			};
+			if spec.flags.grouping && base == Base::new(10) {
+				digits = group_digits(&digits, ',', 3);
+			} else if spec.flags.grouping && base == Base::new(2) {
+				/* binary digits are grouped in nibbles */
+				digits = group_digits(&digits, '_', 4);
+			}
+			let suffix = if spec.flags.ordinal && base == Base::new(10) {
+				/* last two digits, and whether there are more, decide suffix in every locale */
+				let number = if magnitude >= 100 {
+					magnitude % 100 + 100
+				} else {
+					magnitude
+				};
+				locale.ordinal_suffix(number as usize)
+			} else {
+				""
+			};
+			/* zero padding goes after sign and prefix, and is disabled by precision */
+			let zero = spec.flags.zero && !left && precision.is_none();
+			return Ok(pad_number(&format!("{}{}", sign, prefix), &digits, suffix, width, left, zero));
+		},
+	};
+	Ok(format_text(&text, FormatOptions::new(match width {
+		Some(width) if left => FormatDirection::Left(width),
+		Some(width) => FormatDirection::Right(width),
+		None => FormatDirection::None,
+	}, None)))
+}
+
+/// Formats absolute value of floating point number for conversion `f`, `F`, `e`, `E`, `g`, `G`, `a` or `A`,
+/// returning prefix (`0x` for hexadecimal conversions) and the rest.
+fn float_text(
+	value: f64,
+	conversion: char,
+	precision: Option<usize>,
+	flags: &CFormatFlags,
+) -> (&'static str, String) {
+	let case = if conversion.is_ascii_uppercase() {
+		Case::Upper
+	} else {
+		Case::Lower
+	};
+	if !value.is_finite() {
+		let text = if value.is_nan() {
+			"nan"
+		} else {
+			"inf"
+		};
+		return ("", match case {
+			Case::Lower => text.to_string(),
+			Case::Upper => text.to_ascii_uppercase(),
+		});
+	}
+	let mut text = match conversion.to_ascii_lowercase() {
+		'f' => ftoa(value, precision.unwrap_or(6)),
+		'e' => etoa(value, precision.unwrap_or(6), Case::Lower),
+		'g' => {
+			/* precision is number of significant digits */
+			let precision = precision.unwrap_or(6).max(1);
+			let scientific = etoa(value, precision - 1, Case::Lower);
+			let exponent = scientific
+				.rsplit_once('e')
+				.and_then(|e| atoi_signed(e.1.trim_start_matches('+'), Base::new(10), false).ok())
+				.unwrap_or(0);
+			/* exponent before rounding */
+			let exact_exponent = format!("{:e}", value)
+				.rsplit_once('e')
+				.and_then(|e| atoi_signed(e.1, Base::new(10), false).ok())
+				.unwrap_or(exponent);
+			let mut text = if exact_exponent < exponent && exponent == precision as isize {
+				/* like glibc, keep zero fractional digits of fixed notation when rounding carries into scientific notation */
+				etoa(value, 0, Case::Lower)
+			} else if exponent < -4 || exponent >= precision as isize {
+				scientific
+			} else {
+				ftoa(value, (precision as isize - 1 - exponent) as usize)
+			};
+			if !flags.alternate && text.contains('.') {
+				/* remove trailing zeros of fractional part */
+				let exponent_start = text
+					.find(['e', 'E'])
+					.unwrap_or(text.len());
+				let exponent = text.split_off(exponent_start);
+				text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+				text.push_str(&exponent);
+			}
+			text
+		},
+		_ => return (match case {
+			Case::Lower => "0x",
+			Case::Upper => "0X",
+		}, hex_float_text(value, precision, flags.alternate, case)),
+	};
+	if flags.alternate && !text.contains('.') {
+		/* alternate form always has decimal point */
+		let point = text
+			.find(['e', 'E'])
+			.unwrap_or(text.len());
+		text.insert(point, '.');
+	}
+	if flags.grouping && (conversion == 'f' || conversion == 'F' || !text.contains(['e', 'E'])) {
+		let integer = text
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(text.len());
+		text.replace_range(..integer, &group_digits(&text[..integer], ',', 3));
+	}
+	if case == Case::Upper {
+		text.make_ascii_uppercase();
+	}
+	("", text)
+}
+
+/// Formats absolute value of finite floating point number in hexadecimal form without `0x` prefix, like `1.8p+0`.
+/// Without precision, all significant digits are written. Otherwise mantissa is rounded half to even.
+fn hex_float_text(
+	value: f64,
+	precision: Option<usize>,
+	alternate: bool,
+	case: Case,
+) -> String {
+	let bits = value.to_bits();
+	let biased = (bits >> 52) as i32;
+	let mut fraction = bits & ((1 << 52) - 1);
+	let (mut leading, exponent) = match (biased, fraction) {
+		(0, 0) => (0, 0),
+		(0, _) => (0, -1022),
+		_ => (1, biased - 1023),
+	};
+	let mut digits = 13;
+	if let Some(precision) = precision.filter(|e| *e < 13) {
+		let shift = 52 - 4 * precision as u32;
+		let half = 1u64 << (shift - 1);
+		let remainder = fraction & ((1 << shift) - 1);
+		fraction >>= shift;
+		/* without fractional digits, parity of leading digit decides ties */
+		let odd = if precision == 0 {
+			leading % 2 == 1
+		} else {
+			fraction % 2 == 1
+		};
+		if remainder > half || (remainder == half && odd) {
+			fraction += 1;
+		}
+		if fraction >> (4 * precision) != 0 {
+			/* carry into leading digit */
+			leading += 1;
+			fraction = 0;
+		}
+		digits = precision;
+	}
+	let mut fraction_text = if digits == 0 {
+		String::new()
+	} else {
+		format_text(&itoa_unsigned(fraction as usize, Base::new(16), case), FormatOptions::new(FormatDirection::Right(digits), Some('0')))
+	};
+	match precision {
+		None => fraction_text.truncate(fraction_text.trim_end_matches('0').len()),
+		Some(precision) => fraction_text.push_str(&"0".repeat(precision.saturating_sub(digits))),
+	}
+	let mut result = itoa_unsigned(leading, Base::new(16), case);
+	if !fraction_text.is_empty() || alternate {
+		result.push('.');
+	}
+	result.push_str(&fraction_text);
+	result.push(match case {
+		Case::Lower => 'p',
+		Case::Upper => 'P',
+	});
+	if exponent >= 0 {
+		result.push('+');
+	}
+	result.push_str(&itoa_signed(exponent as isize, Base::new(10), Case::Lower));
+	result
+}
+
+/// Pads number, consisting of sign and prefix, digits and suffix, to width.
+/// Zeros are inserted between prefix and digits.
+fn pad_number(
+	prefix: &str,
+	digits: &str,
+	suffix: &str,
+	width: Option<usize>,
+	left: bool,
+	zero: bool,
+) -> String {
+	let width = width.unwrap_or(0);
+	let length = prefix.chars().count() + digits.chars().count() + suffix.chars().count();
+	let padding = width.saturating_sub(length);
+	if zero {
+		format!("{}{}{}{}", prefix, "0".repeat(padding), digits, suffix)
+	} else if left {
+		format!("{}{}{}{}", prefix, digits, suffix, " ".repeat(padding))
+	} else {
+		format!("{}{}{}{}", " ".repeat(padding), prefix, digits, suffix)
+	}
+}
+
+/// Formats the string. Uses C-style formatting.
+/// Conversion specification is `%[flags][width][.precision][length]conversion`, where width and precision
+/// may be `*` to take them from `CFormatArgument::IntSize` argument.
+/// Like in POSIX, `%n$` and `*m$` select argument by position, starting from 1, and can't be mixed with `%` and `*`
+/// taking arguments in order.
+/// Flags are `-`, `+`, ` `, `#`, `0` and POSIX `'` in any order.
+/// Non-standard `~` flag appends English ordinal suffix to decimal conversions (`%~d` gives `1st`, `2nd`, ...);
+/// see `c_format_localized` for other locales.
+/// Formatting stops at invalid specification or argument, returning text formatted before it; see `try_c_format`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::c_format;
+/// 
+/// assert_eq!(c_format("*%d*".to_string(),
+///     vec![
+///         CFormatArgument::Int32(959),
+///     ]
+/// ), "*959*");
+/// assert_eq!(c_format("*%2d*".to_string(),
+///     vec![
+///         CFormatArgument::Int32(959),
+///     ]
+/// ), "*959*");
+/// assert_eq!(c_format("*%10d*".to_string(),
+///     vec![
+///         CFormatArgument::Int32(959),
+///     ]
+/// ), "*       959*");
+/// assert_eq!(c_format("*%-10d*".to_string(),
+///     vec![
+///         CFormatArgument::Int32(959),
+///     ]
+/// ), "*959       *");
+/// assert_eq!(c_format("%~d place".to_string(),
+///     vec![
+///         CFormatArgument::Int32(22),
+///     ]
+/// ), "22nd place");
+/// assert_eq!(c_format("*%~-6u*".to_string(),
+///     vec![
+///         CFormatArgument::UInt32(13),
+///     ]
+/// ), "*13th  *");
+/// assert_eq!(c_format("%.3s|%.5d|%8.3d|%.*s".to_string(),
+///     vec![
+///         CFormatArgument::String("abcdef".to_string()),
+///         CFormatArgument::Int32(-42),
+///         CFormatArgument::Int32(7),
+///         CFormatArgument::IntSize(2),
+///         CFormatArgument::String("xyz".to_string()),
+///     ]
+/// ), "abc|-00042|     007|xy");
+/// assert_eq!(c_format("%-08d|%+d|% d|%#x|%#o|%+06d|%'d".to_string(),
+///     vec![
+///         CFormatArgument::Int32(42),
+///         CFormatArgument::Int32(42),
+///         CFormatArgument::Int32(42),
+///         CFormatArgument::UInt32(31),
+///         CFormatArgument::UInt32(8),
+///         CFormatArgument::Int32(-42),
+///         CFormatArgument::Int32(1234567),
+///     ]
+/// ), "42      |+42| 42|0x1f|010|-00042|1,234,567");
+/// assert_eq!(c_format("%2$s %1$s|%3$*4$d|%3$-*4$d|".to_string(),
+///     vec![
+///         CFormatArgument::String("Bond".to_string()),
+///         CFormatArgument::String("James".to_string()),
+///         CFormatArgument::Int32(7),
+///         CFormatArgument::IntSize(4),
+///     ]
+/// ), "James Bond|   7|7   |");
+/// ```
+pub fn c_format(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+) -> String {
+	/* on error, text formatted before failed specification is returned */
+	match format_arguments(&format, &arguments, false, false, None, Locale::English) {
+		Ok(result) | Err((result, _)) => result,
+	}
+}
+
+/// Formats the string like `c_format`, but returns `Err(FormatError)` instead of truncated string
+/// if specification is incomplete or invalid, arguments are missing or don't match conversions,
+/// or not all arguments are used, including arguments skipped by positional arguments.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::CFormatArgumentKind;
+/// use rustrings::format::FormatError;
+/// use rustrings::format::try_c_format;
+/// 
+/// assert_eq!(try_c_format("%d apples".to_string(), vec![CFormatArgument::Int32(3)]), Ok("3 apples".to_string()));
+/// assert_eq!(try_c_format("x = %5u".to_string(), vec![CFormatArgument::Int32(3)]), Err(FormatError::Mismatch {
+///     offset: 4,
+///     spec: "%5u".to_string(),
+///     index: 0,
+///     expected: CFormatArgumentKind::UInt32,
+///     supplied: CFormatArgumentKind::Int32,
+/// }));
+/// assert_eq!(try_c_format("100%".to_string(), vec![]), Err(FormatError::Incomplete {
+///     offset: 3,
+///     spec: "%".to_string(),
+/// }));
+/// ```
+pub fn try_c_format(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+) -> Result<String, FormatError> {
+	format_arguments(&format, &arguments, true, false, None, Locale::English).map_err(|e| e.1)
+}
+
+/// Formats the string like `c_format`, but coerces arguments like C program would do:
+/// integer conversions accept any integer, character or pointer argument, converted to type of conversion
+/// like C cast (so `%u` prints `Int32(-1)` as `4294967295`, and `%hhd` prints `Int32(300)` as `44`),
+/// `%c` prints integer as code point, `%p` prints integer as address, and `*` takes any integer.
+/// Floating point and string arguments are not coerced.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::c_format_coerced;
+/// 
+/// assert_eq!(c_format_coerced("%u|%d|%c|%x|%*d".to_string(),
+///     vec![
+///         CFormatArgument::Int32(-1),
+///         CFormatArgument::UInt8(200),
+///         CFormatArgument::Int32(0x41),
+///         CFormatArgument::Character('z'),
+///         CFormatArgument::Int32(3),
+///         CFormatArgument::Int64(7),
+///     ]
+/// ), "4294967295|200|A|7a|  7");
+/// ```
+pub fn c_format_coerced(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+) -> String {
+	match format_arguments(&format, &arguments, false, true, None, Locale::English) {
+		Ok(result) | Err((result, _)) => result,
+	}
+}
+
+/// Formats the string like `c_format`, but `~` flag appends ordinal suffix of `locale`.
+/// Other conversions don't depend on locale.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::Locale;
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::c_format_localized;
+/// 
+/// let arguments = vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2), CFormatArgument::Int32(22)];
+/// assert_eq!(c_format_localized("%~d|%~d|%~d".to_string(), arguments.clone(), Locale::English), "1st|2nd|22nd");
+/// assert_eq!(c_format_localized("%~d|%~d|%~d".to_string(), arguments.clone(), Locale::French), "1er|2e|22e");
+/// assert_eq!(c_format_localized("%~d|%~d|%~d".to_string(), arguments.clone(), Locale::German), "1.|2.|22.");
+/// assert_eq!(c_format_localized("%~d|%~d|%~d".to_string(), arguments, Locale::Spanish), "1.º|2.º|22.º");
+/// ```
+pub fn c_format_localized(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+	locale: Locale,
+) -> String {
+	match format_arguments(&format, &arguments, false, false, None, locale) {
+		Ok(result) | Err((result, _)) => result,
+	}
+}
+
+/// Formats the string like `c_format`, with custom conversions registered in `registry`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::net::Ipv4Addr;
+/// 
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::CFormatCustom;
+/// use rustrings::format::CFormatRegistry;
+/// use rustrings::format::CFormatSpec;
+/// use rustrings::format::c_format_with;
+/// 
+/// let mut registry = CFormatRegistry::new();
+/// registry.register('I', |_: &CFormatSpec, _, _, value: &dyn CFormatCustom| {
+///     value.as_any().downcast_ref::<Ipv4Addr>().map_or(String::new(), Ipv4Addr::to_string)
+/// });
+/// assert_eq!(c_format_with("host %-12I|%d".to_string(),
+///     vec![
+///         CFormatArgument::Custom(Box::new(Ipv4Addr::new(10, 0, 0, 1))),
+///         CFormatArgument::Int32(80),
+///     ],
+///     &registry
+/// ), "host 10.0.0.1    |80");
+/// ```
+pub fn c_format_with(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+	registry: &CFormatRegistry,
+) -> String {
+	match format_arguments(&format, &arguments, false, false, Some(registry), Locale::English) {
+		Ok(result) | Err((result, _)) => result,
+	}
+}
+
+/// Formats the string like `try_c_format`, with custom conversions registered in `registry`.
+/// Custom conversions without handler are invalid.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::CFormatCustom;
+/// use rustrings::format::CFormatRegistry;
+/// use rustrings::format::CFormatSpec;
+/// use rustrings::format::FormatError;
+/// use rustrings::format::try_c_format_with;
+/// 
+/// let mut registry = CFormatRegistry::new();
+/// registry.register_named("yes", |_: &CFormatSpec, _, _, value: &dyn CFormatCustom| {
+///     match value.as_any().downcast_ref::<bool>() {
+///         Some(true) => "yes".to_string(),
+///         _ => "no".to_string(),
+///     }
+/// });
+/// assert_eq!(try_c_format_with("%{yes}".to_string(), vec![CFormatArgument::Custom(Box::new(true))], &registry), Ok("yes".to_string()));
+/// assert_eq!(try_c_format_with("%{no}".to_string(), vec![], &registry), Err(FormatError::InvalidSpecifier {
+///     offset: 0,
+///     spec: "%{no}".to_string(),
+/// }));
+/// ```
+pub fn try_c_format_with(
+	format: String,
+	arguments: Vec<CFormatArgument>,
+	registry: &CFormatRegistry,
+) -> Result<String, FormatError> {
+	format_arguments(&format, &arguments, true, false, Some(registry), Locale::English).map_err(|e| e.1)
+}
+
+/// Checks that specification doesn't mix positional and sequential arguments with itself
+/// and with previous specifications, whose kind is stored in `positional`.
+fn spec_positional(
+	spec: &CFormatSpec,
+	positional: &mut Option<bool>,
+) -> bool {
+	let current = spec.argument.is_some();
+	let mut takes_arguments = current || !matches!(spec.specifier, CFormatTypeSpecifier::Escape | CFormatTypeSpecifier::None);
+	for count in [&spec.width, &spec.precision] {
+		match count {
+			Some(CFormatCount::Argument) if current => return false,
+			Some(CFormatCount::Position(_)) if !current => return false,
+			Some(CFormatCount::Argument | CFormatCount::Position(_)) => takes_arguments = true,
+			_ => {},
+		}
+	}
+	!takes_arguments || *positional.get_or_insert(current) == current
+}
+
+/// Segment of format string, with its byte span in format string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CFormatSegment {
+	/// Literal text.
+	Literal {
+		text: String,
+		span: Range<usize>,
+	},
+	
+	/// Conversion specification, including `%%`.
+	Conversion {
+		spec: CFormatSpec,
+		span: Range<usize>,
+	},
+}
+
+impl CFormatSegment {
+	/// Returns byte span of segment in format string.
+	pub fn span(&self) -> &Range<usize> {
+		match self {
+			CFormatSegment::Literal { span, .. } | CFormatSegment::Conversion { span, .. } => span,
+		}
+	}
+	
+	/// Writes segment back to format string, escaping `%` in literal text.
+	pub fn to_format(&self) -> String {
+		match self {
+			CFormatSegment::Literal { text, .. } => text.replace('%', "%%"),
+			CFormatSegment::Conversion { spec, .. } => spec.to_format(),
+		}
+	}
+}
+
+/// Parser of format string for `c_format`, iterating over its segments.
+/// After incomplete specification, or length modifier not accepted by conversion, returns error and stops.
+/// Unknown conversions are returned with `CFormatTypeSpecifier::None`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatParser;
+/// use rustrings::format::CFormatSegment;
+/// use rustrings::format::FormatError;
+/// 
+/// let segments: Vec<CFormatSegment> = CFormatParser::new("id=%-5d|%.2f%%")
+///     .collect::<Result<_, FormatError>>()
+///     .unwrap();
+/// assert_eq!(segments.len(), 5);
+/// assert_eq!(segments[1].span(), &(3..7));
+/// assert_eq!(segments.iter().map(CFormatSegment::to_format).collect::<String>(), "id=%-5d|%.2f%%");
+/// assert!(CFormatParser::new("50%").last().unwrap().is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CFormatParser<'a> {
+	/// Format string.
+	format: &'a str,
+	
+	/// Characters of format string, which are not parsed yet.
+	iterator: Peekable<CharIndices<'a>>,
+	
+	/// Whether error was returned.
+	failed: bool,
+}
+
+impl<'a> CFormatParser<'a> {
+	/// Constructs CFormatParser.
+	pub fn new(format: &'a str) -> CFormatParser<'a> {
+		CFormatParser {
+			format,
+			iterator: format.char_indices().peekable(),
+			failed: false,
+		}
+	}
+	
+	/// Returns byte offset of next character.
+	fn position(&mut self) -> usize {
+		self.iterator
+			.peek()
+			.map_or(self.format.len(), |e| e.0)
+	}
+}
+
+impl Iterator for CFormatParser<'_> {
+	type Item = Result<CFormatSegment, FormatError>;
+	
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.failed {
+			return None;
+		}
+		let (offset, character) = *self.iterator.peek()?;
+		if character != '%' {
+			while self.iterator.next_if(|e| e.1 != '%').is_some() {}
+			let span = offset..self.position();
+			return Some(Ok(CFormatSegment::Literal {
+				text: self.format[span.clone()].to_string(),
+				span,
+			}));
+		}
+		self.iterator.next();
+		let spec = parse_spec(&mut self.iterator);
+		let span = offset..self.position();
+		Some(match spec {
+			Ok(spec) => Ok(CFormatSegment::Conversion {
+				spec,
+				span,
+			}),
+			Err(incomplete) => {
+				self.failed = true;
+				let spec = self.format[span].to_string();
+				Err(if incomplete {
+					FormatError::Incomplete {
+						offset,
+						spec,
+					}
+				} else {
+					FormatError::InvalidSpecifier {
+						offset,
+						spec,
+					}
+				})
+			},
+		})
+	}
+}
+
+/// Parses format string into segments, stopping at first invalid specification.
+/// Unknown conversions registered in `registry` become custom conversions.
+/// In strict mode, unknown conversions and custom conversions without handler are invalid too.
+fn compile_format(
+	format: &str,
+	strict: bool,
+	registry: Option<&CFormatRegistry>,
+) -> (Vec<CFormatSegment>, Option<FormatError>) {
+	let mut segments = Vec::new();
+	/* whether format string uses positional arguments, known after first specification taking arguments */
+	let mut positional = None;
+	for segment in CFormatParser::new(format) {
+		let segment = segment.map(|e| match e {
+			CFormatSegment::Conversion { mut spec, span } => {
+				if spec.specifier == CFormatTypeSpecifier::None && registry.is_some_and(|e| e.handler(&spec).is_some()) {
+					spec.specifier = CFormatTypeSpecifier::Custom;
+				}
+				CFormatSegment::Conversion {
+					spec,
+					span,
+				}
+			},
+			segment => segment,
+		});
+		let unknown = |spec: &CFormatSpec| match spec.specifier {
+			CFormatTypeSpecifier::None => true,
+			CFormatTypeSpecifier::Custom => registry.and_then(|e| e.handler(spec)).is_none(),
+			_ => false,
+		};
+		let error = match segment {
+			Err(error) => error,
+			Ok(CFormatSegment::Conversion { spec, span }) if strict && unknown(&spec) => FormatError::InvalidSpecifier {
+				offset: span.start,
+				spec: format[span].to_string(),
+			},
+			Ok(CFormatSegment::Conversion { spec, span }) if !spec_positional(&spec, &mut positional) => FormatError::MixedArguments {
+				offset: span.start,
+				spec: format[span].to_string(),
+			},
+			Ok(segment) => {
+				segments.push(segment);
+				continue;
+			},
+		};
+		return (segments, Some(error));
+	}
+	(segments, None)
+}
+
+/// Renders segments of format string, returning text rendered before error along with error.
+/// If `strict` is set, unused arguments are error.
+fn render_format(
+	format: &str,
+	segments: &[CFormatSegment],
+	arguments: &[CFormatArgument],
+	strict: bool,
+	coerce: bool,
+	registry: Option<&CFormatRegistry>,
+	locale: Locale,
+) -> Result<String, (String, FormatError)> {
+	let mut result = String::new();
+	let mut args_iterator = CFormatArguments::new(arguments, coerce);
+	for segment in segments {
+		let (spec, span) = match segment {
+			CFormatSegment::Literal { text, .. } => {
+				result.push_str(text);
+				continue;
+			},
+			CFormatSegment::Conversion { spec, span } => (spec, span),
+		};
+		let error = match format_spec(spec, &mut args_iterator, registry, locale) {
+			Ok(formatted) => {
+				result.push_str(&formatted);
+				continue;
+			},
+			Err(SpecError::MissingArgument(index)) => FormatError::MissingArgument {
+				offset: span.start,
+				spec: format[span.clone()].to_string(),
+				index,
+			},
+			Err(SpecError::Mismatch(index, expected, supplied)) => FormatError::Mismatch {
+				offset: span.start,
+				spec: format[span.clone()].to_string(),
+				index,
+				expected,
+				supplied,
+			},
+		};
+		return Err((result, error));
+	}
+	let used = args_iterator.used;
+	/* positional arguments may skip arguments before the last used one */
+	if let Some(index) = args_iterator.taken[..used].iter().position(|e| !e).filter(|_| strict) {
+		return Err((result, FormatError::SkippedArgument {
+			index,
+		}));
+	}
+	if strict && used < arguments.len() {
+		return Err((result, FormatError::ExtraArguments {
+			used,
+			supplied: arguments.len(),
+		}));
+	}
+	Ok(result)
+}
+
+/// Formats the string, returning text formatted before error along with error.
+/// In strict mode, unknown conversions and unused arguments are errors too.
+fn format_arguments(
+	format: &str,
+	arguments: &[CFormatArgument],
+	strict: bool,
+	coerce: bool,
+	registry: Option<&CFormatRegistry>,
+	locale: Locale,
+) -> Result<String, (String, FormatError)> {
+	let (segments, error) = compile_format(format, strict, registry);
+	/* arguments of valid part are checked before invalid specification */
+	let result = render_format(format, &segments, arguments, strict && error.is_none(), coerce, registry, locale)?;
+	match error {
+		Some(error) => Err((result, error)),
+		None => Ok(result),
+	}
+}
+
+/// Handler of custom conversion, registered in `CFormatRegistry`.
+/// Implemented for closures with the same arguments.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::CFormatCustom;
+/// use rustrings::format::CFormatHandler;
+/// use rustrings::format::CFormatRegistry;
+/// use rustrings::format::CFormatSpec;
+/// use rustrings::format::c_format_with;
+/// 
+/// #[derive(Debug, Clone)]
+/// struct Uuid(u128);
+/// 
+/// struct UuidHandler;
+/// 
+/// impl CFormatHandler for UuidHandler {
+///     fn format(
+///         &self,
+///         spec: &CFormatSpec,
+///         _width: Option<usize>,
+///         _precision: Option<usize>,
+///         value: &dyn CFormatCustom,
+///     ) -> String {
+///         let Some(Uuid(value)) = value.as_any().downcast_ref::<Uuid>() else {
+///             return String::new();
+///         };
+///         let text = format!("{:032x}", value);
+///         let text = format!("{}-{}-{}-{}-{}", &text[..8], &text[8..12], &text[12..16], &text[16..20], &text[20..]);
+///         if spec.flags.alternate {
+///             format!("{{{}}}", text)
+///         } else {
+///             text
+///         }
+///     }
+/// }
+/// 
+/// let mut registry = CFormatRegistry::new();
+/// registry.register_named("uuid", UuidHandler);
+/// assert_eq!(c_format_with("%#{uuid}".to_string(), vec![CFormatArgument::Custom(Box::new(Uuid(0x123e4567_e89b_12d3_a456_426614174000)))], &registry),
+///     "{123e4567-e89b-12d3-a456-426614174000}");
+/// ```
+pub trait CFormatHandler {
+	/// Formats value of `CFormatArgument::Custom` for conversion specification.
+	/// Width and precision are resolved from arguments, result is padded to width afterwards.
+	fn format(
+		&self,
+		spec: &CFormatSpec,
+		width: Option<usize>,
+		precision: Option<usize>,
+		value: &dyn CFormatCustom,
+	) -> String;
+}
+
+impl<F: Fn(&CFormatSpec, Option<usize>, Option<usize>, &dyn CFormatCustom) -> String> CFormatHandler for F {
+	fn format(
+		&self,
+		spec: &CFormatSpec,
+		width: Option<usize>,
+		precision: Option<usize>,
+		value: &dyn CFormatCustom,
+	) -> String {
+		self(spec, width, precision, value)
+	}
+}
+
+/// Custom conversions for `c_format_with`, `try_c_format_with` and `CFormat::with_registry`,
+/// like `register_printf_specifier` in glibc. Conversions are unused conversion characters or names (`%{name}`),
+/// which take `CFormatArgument::Custom`. Custom conversions don't accept length modifiers.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormatArgument;
+/// use rustrings::format::CFormatCustom;
+/// use rustrings::format::CFormatRegistry;
+/// use rustrings::format::CFormatSpec;
+/// use rustrings::format::c_format_with;
+/// 
+/// let mut registry = CFormatRegistry::new();
+/// registry
+///     .register('y', |_: &CFormatSpec, _, _, value: &dyn CFormatCustom| format!("{:?}", value))
+///     .register_named("upper", |_: &CFormatSpec, _, precision: Option<usize>, value: &dyn CFormatCustom| {
+///         let text = value.as_any().downcast_ref::<&str>().map_or(String::new(), |e| e.to_uppercase());
+///         text.chars().take(precision.unwrap_or(usize::MAX)).collect()
+///     });
+/// assert_eq!(c_format_with("%y|%5.2{upper}|".to_string(),
+///     vec![
+///         CFormatArgument::Custom(Box::new(Some(1))),
+///         CFormatArgument::Custom(Box::new("abc")),
+///     ],
+///     &registry
+/// ), "Some(1)|   AB|");
+/// ```
+#[derive(Default)]
+pub struct CFormatRegistry {
+	/// Handlers of conversion characters.
+	conversions: HashMap<char, Box<dyn CFormatHandler + Send + Sync>>,
+	
+	/// Handlers of conversion names.
+	names: HashMap<String, Box<dyn CFormatHandler + Send + Sync>>,
+}
+
+impl CFormatRegistry {
+	/// Constructs empty CFormatRegistry.
+	pub fn new() -> CFormatRegistry {
+		CFormatRegistry::default()
+	}
+	
+	/// Registers handler of conversion character, replacing previous one, and returns chain.
+	/// Panics if character is standard conversion, flag, length modifier or digit.
+	pub fn register(
+		&mut self,
+		conversion: char,
+		handler: impl CFormatHandler + Send + Sync + 'static,
+	) -> &mut CFormatRegistry {
+		let unused = type_specifier(CFormatLength::None, conversion) == Some(CFormatTypeSpecifier::None)
+			&& !"-+ #0'~*.$hlzjtLw{".contains(conversion)
+			&& !conversion.is_ascii_digit();
+		assert!(unused, "conversion character is already used ({})", conversion);
+		self.conversions.insert(conversion, Box::new(handler));
+		self
+	}
+	
+	/// Registers handler of conversion name `%{name}`, replacing previous one, and returns chain.
+	/// Panics if name is empty or contains `}`.
+	pub fn register_named(
+		&mut self,
+		name: &str,
+		handler: impl CFormatHandler + Send + Sync + 'static,
+	) -> &mut CFormatRegistry {
+		assert!(!name.is_empty() && !name.contains('}'), "invalid conversion name ({})", name);
+		self.names.insert(name.to_string(), Box::new(handler));
+		self
+	}
+	
+	/// Returns handler of specification's conversion character or name.
+	fn handler(
+		&self,
+		spec: &CFormatSpec,
+	) -> Option<&dyn CFormatHandler> {
+		let handler = match &spec.name {
+			Some(name) => self.names.get(name),
+			None => self.conversions.get(&spec.conversion),
+		};
+		handler.map(|e| e.as_ref() as &dyn CFormatHandler)
+	}
+}
+
+impl Debug for CFormatRegistry {
+	fn fmt(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+	) -> fmt::Result {
+		f.debug_struct("CFormatRegistry")
+			.field("conversions", &self.conversions.keys().collect::<Vec<_>>())
+			.field("names", &self.names.keys().collect::<Vec<_>>())
+			.finish()
+	}
+}
+
+/// Format string compiled once for `c_format`, which can be rendered with many argument lists
+/// without parsing it again.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::CFormat;
+/// use rustrings::format::CFormatArgument;
+/// 
+/// let format = CFormat::new("[%-5s] %3d%%").unwrap();
+/// assert_eq!(format.format(&[CFormatArgument::String("cpu".to_string()), CFormatArgument::Int32(42)]), "[cpu  ]  42%");
+/// assert_eq!(format.format(&[CFormatArgument::String("disk".to_string()), CFormatArgument::Int32(7)]), "[disk ]   7%");
+/// assert_eq!(format.segments().len(), 5);
+/// assert!(CFormat::new("%y").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CFormat {
+	/// Format string.
+	format: String,
+	
+	/// Literal text and conversion specifications.
+	segments: Vec<CFormatSegment>,
+	
+	/// Whether arguments are coerced like in C.
+	coercion: bool,
+	
+	/// Custom conversions.
+	registry: Option<Arc<CFormatRegistry>>,
+	
+	/// Locale of ordinal suffixes.
+	locale: Locale,
+}
+
+impl CFormat {
+	/// Compiles format string, returning `Err(FormatError)` if specification is incomplete or invalid,
+	/// or positional and sequential arguments are mixed.
+	pub fn new(format: &str) -> Result<CFormat, FormatError> {
+		match compile_format(format, true, None) {
+			(segments, None) => Ok(CFormat {
+				format: format.to_string(),
+				segments,
+				coercion: false,
+				registry: None,
+				locale: Locale::English,
+			}),
+			(_, Some(error)) => Err(error),
+		}
+	}
+	
+	/// Compiles format string with custom conversions registered in `registry`,
+	/// like `CFormat::new`. Custom conversions without handler are invalid.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use std::sync::Arc;
+	/// 
+	/// use rustrings::format::CFormat;
+	/// use rustrings::format::CFormatArgument;
+	/// use rustrings::format::CFormatCustom;
+	/// use rustrings::format::CFormatRegistry;
+	/// use rustrings::format::CFormatSpec;
+	/// 
+	/// let mut registry = CFormatRegistry::new();
+	/// registry.register('Q', |_: &CFormatSpec, _, _, value: &dyn CFormatCustom| {
+	///     value.as_any().downcast_ref::<(i32, i32)>().map_or(String::new(), |e| format!("{}/{}", e.0, e.1))
+	/// });
+	/// let registry = Arc::new(registry);
+	/// let format = CFormat::with_registry("ratio %Q", registry.clone()).unwrap();
+	/// assert_eq!(format.format(&[CFormatArgument::Custom(Box::new((3, 4)))]), "ratio 3/4");
+	/// assert!(CFormat::with_registry("%{ratio}", registry).is_err());
+	/// ```
+	pub fn with_registry(
+		format: &str,
+		registry: Arc<CFormatRegistry>,
+	) -> Result<CFormat, FormatError> {
+		match compile_format(format, true, Some(&registry)) {
+			(segments, None) => Ok(CFormat {
+				format: format.to_string(),
+				segments,
+				coercion: false,
+				registry: Some(registry),
+				locale: Locale::English,
+			}),
+			(_, Some(error)) => Err(error),
+		}
+	}
+	
+	/// Returns segments of compiled format string.
+	pub fn segments(&self) -> &[CFormatSegment] {
+		&self.segments
+	}
+	
+	/// Returns whether arguments are coerced like in C. See `c_format_coerced`.
+	pub fn get_coercion(&self) -> bool {
+		self.coercion
+	}
+	
+	/// Sets whether arguments are coerced like in C, and returns chain. See `c_format_coerced`.
+	pub fn set_coercion(
+		&mut self,
+		coercion: bool,
+	) -> &mut CFormat {
+		self.coercion = coercion;
+		self
+	}
+	
+	/// Returns locale of ordinal suffixes. See `c_format_localized`.
+	pub fn get_locale(&self) -> Locale {
+		self.locale
+	}
+	
+	/// Sets locale of ordinal suffixes, and returns chain. See `c_format_localized`.
+	pub fn set_locale(
+		&mut self,
+		locale: Locale,
+	) -> &mut CFormat {
+		self.locale = locale;
+		self
+	}
+	
+	/// Formats arguments like `c_format`.
+	pub fn format(
+		&self,
+		arguments: &[CFormatArgument],
+	) -> String {
+		match render_format(&self.format, &self.segments, arguments, false, self.coercion, self.registry.as_deref(), self.locale) {
+			Ok(result) | Err((result, _)) => result,
+		}
+	}
+	
+	/// Formats arguments like `try_c_format`.
+	pub fn try_format(
+		&self,
+		arguments: &[CFormatArgument],
+	) -> Result<String, FormatError> {
+		render_format(&self.format, &self.segments, arguments, true, self.coercion, self.registry.as_deref(), self.locale).map_err(|e| e.1)
+	}
+}
+
+/// Marks argument, which is not used by format string, in `c_format!` checks.
+#[doc(hidden)]
+pub const UNUSED_ARGUMENT: u8 = u8::MAX;
+
+/// Position of argument taken in order (`*`), in specifications parsed by `c_format!`.
+const NEXT_ARGUMENT: usize = usize::MAX;
+
+/// Conversion specification parsed at compile time by `c_format!`.
+/// Positions of arguments start from 1, zero means that argument is not used or taken in order.
+struct ConstSpec {
+	/// Byte offset after specification.
+	end: usize,
+	
+	/// Position of converted argument (`n$`).
+	argument: usize,
+	
+	/// Position of width argument, or `NEXT_ARGUMENT` for `*`.
+	width: usize,
+	
+	/// Position of precision argument, or `NEXT_ARGUMENT` for `*`.
+	precision: usize,
+	
+	/// Code of converted argument variant, or `UNUSED_ARGUMENT` for `%%`.
+	kind: u8,
+}
+
+/// Parses decimal number at `start`, returning it and its end.
+const fn const_number(
+	bytes: &[u8],
+	start: usize,
+) -> (usize, usize) {
+	let mut value = 0usize;
+	let mut end = start;
+	while end < bytes.len() && bytes[end].is_ascii_digit() {
+		value = value
+			.saturating_mul(10)
+			.saturating_add((bytes[end] - b'0') as usize);
+		end += 1;
+	}
+	(value, end)
+}
+
+/// Parses argument position `n$` at `start`, returning it and its end, or zero and `start` if there is none.
+const fn const_position(
+	bytes: &[u8],
+	start: usize,
+) -> (usize, usize) {
+	let (value, end) = const_number(bytes, start);
+	if value != 0 && end < bytes.len() && bytes[end] == b'$' {
+		(value, end + 1)
+	} else {
+		(0, start)
+	}
+}
+
+/// Parses width or precision at `start`, returning position of its argument and its end.
+const fn const_count(
+	bytes: &[u8],
+	start: usize,
+) -> (usize, usize) {
+	if start < bytes.len() && bytes[start] == b'*' {
+		match const_position(bytes, start + 1) {
+			(0, _) => (NEXT_ARGUMENT, start + 1),
+			position => position,
+		}
+	} else {
+		(0, const_number(bytes, start).1)
+	}
+}
+
+/// Parses conversion specification after `%` at `start`. Panics if it is incomplete or invalid.
+const fn const_spec(
+	bytes: &[u8],
+	start: usize,
+) -> ConstSpec {
+	let (argument, mut end) = const_position(bytes, start);
+	while end < bytes.len() && matches!(bytes[end], b'-' | b'0' | b'+' | b' ' | b'#' | b'\'' | b'~') {
+		end += 1;
+	}
+	let (width, width_end) = const_count(bytes, end);
+	end = width_end;
+	let mut precision = 0;
+	if end < bytes.len() && bytes[end] == b'.' {
+		let (position, precision_end) = const_count(bytes, end + 1);
+		precision = position;
+		end = precision_end;
+	}
+	let mut length = CFormatLength::None;
+	if end + 1 < bytes.len() && bytes[end] == b'h' && bytes[end + 1] == b'h' {
+		length = CFormatLength::Char;
+		end += 2;
+	} else if end + 1 < bytes.len() && bytes[end] == b'l' && bytes[end + 1] == b'l' {
+		length = CFormatLength::LongLong;
+		end += 2;
+	} else if end < bytes.len() && bytes[end] == b'h' {
+		length = CFormatLength::Short;
+		end += 1;
+	} else if end < bytes.len() && bytes[end] == b'l' {
+		length = CFormatLength::Long;
+		end += 1;
+	} else if end < bytes.len() && matches!(bytes[end], b'z' | b'j' | b't' | b'L') {
+		length = match bytes[end] {
+			b'z' => CFormatLength::Size,
+			b'j' => CFormatLength::Max,
+			b't' => CFormatLength::PtrDiff,
+			_ => CFormatLength::LongDouble,
+		};
+		end += 1;
+	} else if end < bytes.len() && bytes[end] == b'w' {
+		let fast = end + 1 < bytes.len() && bytes[end + 1] == b'f';
+		let (bits, bits_end) = const_number(bytes, end + 1 + fast as usize);
+		/* number of bits is checked with conversion */
+		let bits = if bits > u32::MAX as usize {
+			0
+		} else {
+			bits as u32
+		};
+		length = if fast {
+			CFormatLength::FastWidth(bits)
+		} else {
+			CFormatLength::Width(bits)
+		};
+		end = bits_end;
+	}
+	assert!(end < bytes.len(), "c_format!: incomplete conversion specification");
+	/* non-ASCII conversion is unknown */
+	let conversion = if bytes[end].is_ascii() {
+		bytes[end] as char
+	} else {
+		'\0'
+	};
+	let kind = match type_specifier(length, conversion) {
+		None => panic!("c_format!: conversion doesn't accept length modifier"),
+		Some(CFormatTypeSpecifier::None) => panic!("c_format!: unknown conversion"),
+		Some(specifier) => match specifier_kind(&specifier, length, conversion) {
+			Some(kind) => kind as u8,
+			None => UNUSED_ARGUMENT,
+		},
+	};
+	ConstSpec {
+		end: end + 1,
+		argument,
+		width,
+		precision,
+		kind,
+	}
+}
+
+/// Checks format string at compile time, returning code of argument variant with index expected by it
+/// (or `UNUSED_ARGUMENT`) and number of arguments up to the last used one.
+/// Panics if format string is invalid, mixes positional and sequential arguments,
+/// or converts same argument as different variants.
+const fn const_arguments(
+	format: &str,
+	index: usize,
+) -> (u8, usize) {
+	let bytes = format.as_bytes();
+	let mut expected = UNUSED_ARGUMENT;
+	let mut used = 0;
+	let mut next = 0;
+	/* zero if unknown yet, otherwise 1 for arguments taken in order, 2 for positional arguments */
+	let mut mode = 0;
+	let mut offset = 0;
+	while offset < bytes.len() {
+		if bytes[offset] != b'%' {
+			offset += 1;
+			continue;
+		}
+		let spec = const_spec(bytes, offset + 1);
+		offset = spec.end;
+		let positional = spec.argument != 0;
+		let slots = [
+			(spec.width, CFormatArgumentKind::IntSize as u8),
+			(spec.precision, CFormatArgumentKind::IntSize as u8),
+			(spec.argument, spec.kind),
+		];
+		let mut i = 0;
+		while i < slots.len() {
+			let (mut position, kind) = slots[i];
+			i += 1;
+			if i == slots.len() && kind != UNUSED_ARGUMENT && !positional {
+				position = NEXT_ARGUMENT;
+			}
+			if position == 0 {
+				continue;
+			}
+			let current = if position == NEXT_ARGUMENT {
+				1
+			} else {
+				2
+			};
+			assert!((current == 2) == positional && (mode == 0 || mode == current),
+				"c_format!: positional and sequential arguments are mixed");
+			mode = current;
+			let slot = if position == NEXT_ARGUMENT {
+				next += 1;
+				next - 1
+			} else {
+				position - 1
+			};
+			if slot == index {
+				assert!(expected == UNUSED_ARGUMENT || expected == kind, "c_format!: argument is converted as different types");
+				expected = kind;
+			}
+			if slot >= used {
+				used = slot + 1;
+			}
+		}
+	}
+	(expected, used)
+}
+
+/// Returns code of argument variant with index (`CFormatArgumentKind as u8`) expected by format string,
+/// or `UNUSED_ARGUMENT`, if argument is not used. Used by `c_format!`.
+#[doc(hidden)]
+pub const fn expected_argument(
+	format: &str,
+	index: usize,
+) -> u8 {
+	const_arguments(format, index).0
+}
+
+/// Returns number of arguments used by format string. Used by `c_format!`.
+#[doc(hidden)]
+pub const fn argument_count(format: &str) -> usize {
+	const_arguments(format, usize::MAX).1
+}
+
+/// Converts argument of `c_format!`, checking at compile time that it is variant expected by format string.
+#[doc(hidden)]
+pub fn checked_argument<T: CFormatValue, const EXPECTED: u8>(value: T) -> CFormatArgument {
+	const {
+		let kind = T::KIND as u8;
+		/* number of arguments is checked separately, so unused argument is skipped by positional arguments */
+		assert!(EXPECTED != UNUSED_ARGUMENT, "c_format!: argument is not used by format string");
+		assert!(kind == EXPECTED
+			|| (EXPECTED == CFormatArgumentKind::Float64 as u8 && kind == CFormatArgumentKind::Float32 as u8),
+			"c_format!: argument type doesn't match conversion");
+	}
+	value.into()
+}
+
+/// Same as `c_format`, but takes Rust values, converted to `CFormatArgument` with `From`.
+/// If format string is literal, it is checked at compile time, and so are types of arguments,
+/// their number, arguments skipped by positional arguments, and mixing of positional and sequential arguments.
+/// Otherwise, arguments are only converted.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::c_format;
+/// 
+/// let name = "world";
+/// assert_eq!(c_format!("hello, %s! %d %.2f", name, 42, 0.5f32), "hello, world! 42 0.50");
+/// assert_eq!(c_format!("%2$s %1$s", "Bond", "James".to_string()), "James Bond");
+/// let format = "%llu";
+/// assert_eq!(c_format!(format, 7u64), "7");
+/// ```
+/// 
+/// Mismatching arguments don't compile:
+/// 
+/// ```compile_fail
+/// use rustrings::format::c_format;
+/// 
+/// c_format!("%d", "text");
+/// ```
+/// 
+/// ```compile_fail
+/// use rustrings::format::c_format;
+/// 
+/// c_format!("%d %d", 1);
+/// ```
+/// 
+/// ```compile_fail
+/// use rustrings::format::c_format;
+/// 
+/// c_format!("%2$d", 1, 2);
+/// ```
+#[macro_export]
+macro_rules! c_format {
+	(@arguments $format:literal, $index:expr, [$($done:expr),*], $argument:expr $(, $rest:expr)*) => {
+		$crate::c_format!(@arguments $format, $index + 1, [$($done,)* $crate::format::checked_argument::<_, { $crate::format::expected_argument($format, $index) }>($argument)] $(, $rest)*)
+	};
+	(@arguments $format:literal, $index:expr, [$($done:expr),*]) => {
+		{
+			const {
+				assert!($crate::format::argument_count($format) == $index, "c_format!: number of arguments doesn't match format string");
+			}
+			::std::vec![$($done),*]
+		}
+	};
+	($format:literal $(, $argument:expr)* $(,)?) => {
+		$crate::format::c_format(::std::string::String::from($format), $crate::c_format!(@arguments $format, 0usize, [] $(, $argument)*))
+	};
+	($format:expr $(, $argument:expr)* $(,)?) => {
+		$crate::format::c_format(::std::string::String::from($format), ::std::vec![$($crate::format::CFormatArgument::from($argument)),*])
+	};
+}
+
+pub use crate::c_format;
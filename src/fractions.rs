@@ -0,0 +1,395 @@
+//! Fraction formatting and parsing.
+//! 
+//! This module contains rational approximation of floating point numbers and converters for fractions and mixed numbers,
+//! written either in ASCII (`3/8`, `2 1/2`) or with Unicode glyphs (`⅜`, `2 ½`, `¹⁷⁄₃₂`).
+
+use crate::converters::Base;
+use crate::converters::Case;
+use crate::converters::itoa_unsigned;
+use crate::format::FormatOptions;
+use crate::format::format_text;
+
+/// Reduced fraction with positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fraction {
+	/// Numerator, carries sign of fraction.
+	pub numerator: isize,
+	
+	/// Denominator, always positive.
+	pub denominator: usize,
+}
+
+/// Greatest common divisor.
+fn gcd(
+	a: usize,
+	b: usize,
+) -> usize {
+	if b == 0 {
+		a
+	} else {
+		gcd(b, a % b)
+	}
+}
+
+impl Fraction {
+	/// Constructs reduced Fraction, returning `None` if denominator is zero.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::fractions::Fraction;
+	/// 
+	/// assert_eq!(Fraction::new(6, 16), Some(Fraction { numerator: 3, denominator: 8 }));
+	/// assert_eq!(Fraction::new(-4, 2), Some(Fraction { numerator: -2, denominator: 1 }));
+	/// assert_eq!(Fraction::new(1, 0), None);
+	/// ```
+	pub fn new(
+		numerator: isize,
+		denominator: usize,
+	) -> Option<Fraction> {
+		if denominator == 0 {
+			return None;
+		}
+		/* divisor may be 2^63, which doesn't fit isize, so magnitudes are divided */
+		let divisor = gcd(numerator.unsigned_abs(), denominator);
+		let magnitude = numerator.unsigned_abs() / divisor;
+		Some(Fraction {
+			numerator: if numerator < 0 {
+				0isize.wrapping_sub_unsigned(magnitude)
+			} else {
+				magnitude as isize
+			},
+			denominator: denominator / divisor,
+		})
+	}
+	
+	/// Finds fraction closest to value with denominator not greater than `max_denominator`, using continued fractions.
+	/// Returns `None` if value is not finite or doesn't fit.
+	/// 
+	/// Value is rounded to 64 binary digits after point first, so `max_denominator` is limited to `u32::MAX`.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::fractions::Fraction;
+	/// 
+	/// assert_eq!(Fraction::approximate(0.375, 64), Fraction::new(3, 8));
+	/// assert_eq!(Fraction::approximate(-2.5, 64), Fraction::new(-5, 2));
+	/// assert_eq!(Fraction::approximate(std::f64::consts::PI, 1000), Fraction::new(355, 113));
+	/// assert_eq!(Fraction::approximate(0.33, 10), Fraction::new(1, 3));
+	/// ```
+	pub fn approximate(
+		value: f64,
+		max_denominator: usize,
+	) -> Option<Fraction> {
+		if !value.is_finite() {
+			return None;
+		}
+		let bits = value.abs().to_bits();
+		let (mantissa, exponent) = match (bits >> 52) as i32 {
+			0 => ((bits & ((1 << 52) - 1)) as u128, -1074),
+			biased => (((bits & ((1 << 52) - 1)) | (1 << 52)) as u128, biased - 1075),
+		};
+		/* value = integer + fraction / 2^64 */
+		let (mut integer, mut fraction) = if exponent >= 0 {
+			if exponent >= 64 {
+				return None;
+			}
+			(mantissa << exponent, 0)
+		} else if exponent >= -64 {
+			let shift = -exponent as u32;
+			(mantissa >> shift, (mantissa & ((1 << shift) - 1)) << (64 - shift))
+		} else {
+			match -exponent as u32 - 64 {
+				shift if shift >= 128 => (0, 0),
+				shift => (0, (mantissa + (1 << (shift - 1))) >> shift),
+			}
+		};
+		if fraction >> 64 != 0 {
+			integer += 1;
+			fraction = 0;
+		}
+		let (p, q) = best_rational(fraction, max_denominator.clamp(1, u32::MAX as usize) as u128);
+		let numerator = integer
+			.checked_mul(q)?
+			.checked_add(p)?;
+		if numerator > isize::MAX as u128 {
+			return None;
+		}
+		let numerator = numerator as isize;
+		Fraction::new(if value < 0.0 {
+			-numerator
+		} else {
+			numerator
+		}, q as usize)
+	}
+	
+	/// Converts Fraction into floating point number.
+	pub fn to_f64(&self) -> f64 {
+		self.numerator as f64 / self.denominator as f64
+	}
+}
+
+/// Finds fraction `p/q` closest to `fraction / 2^64` with `q` not greater than `max_denominator` (at most `u32::MAX`).
+fn best_rational(
+	fraction: u128,
+	max_denominator: u128,
+) -> (u128, u128) {
+	let denominator = 1u128 << 64;
+	let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+	let (mut n, mut d) = (fraction, denominator);
+	loop {
+		let a = n / d;
+		let q2 = q0 + a * q1;
+		if q2 > max_denominator {
+			break;
+		}
+		(p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+		(n, d) = (d, n - a * d);
+		if d == 0 {
+			/* exact */
+			return (p1, q1);
+		}
+	}
+	/* best semiconvergent or last convergent */
+	let k = (max_denominator - q0) / q1;
+	let (p2, q2) = (p0 + k * p1, q0 + k * q1);
+	let error = |p: u128, q: u128| (p * denominator).abs_diff(fraction * q);
+	if error(p1, q1) * q2 <= error(p2, q2) * q1 {
+		(p1, q1)
+	} else {
+		(p2, q2)
+	}
+}
+
+/// Fraction writing style.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FractionStyle {
+	/// Improper ASCII fraction, e.g. `5/2`.
+	Ascii,
+	
+	/// Mixed number in ASCII, e.g. `2 1/2`.
+	Mixed,
+	
+	/// Mixed number with Unicode vulgar fraction or superscript/subscript glyphs, e.g. `2 ½` or `¹⁷⁄₃₂`.
+	Unicode,
+}
+
+/// Unicode vulgar fractions as `(numerator, denominator, glyph)`.
+const VULGAR_FRACTIONS: [(usize, usize, char); 18] = [
+	(1, 2, '½'), (1, 3, '⅓'), (2, 3, '⅔'), (1, 4, '¼'), (3, 4, '¾'), (1, 5, '⅕'),
+	(2, 5, '⅖'), (3, 5, '⅗'), (4, 5, '⅘'), (1, 6, '⅙'), (5, 6, '⅚'), (1, 7, '⅐'),
+	(1, 8, '⅛'), (3, 8, '⅜'), (5, 8, '⅝'), (7, 8, '⅞'), (1, 9, '⅑'), (1, 10, '⅒'),
+];
+
+/// Superscript digits.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Subscript digits.
+const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// Fraction slash, used between superscript and subscript digits.
+const FRACTION_SLASH: char = '⁄';
+
+/// Writes number with digits from given table.
+fn digits_with(
+	number: usize,
+	digits: &[char; 10],
+) -> String {
+	itoa_unsigned(number, Base::new(10), Case::Lower)
+		.bytes()
+		.map(|e| digits[(e - b'0') as usize])
+		.collect()
+}
+
+/// Formats fraction in given style.
+/// Whole numbers are written without fractional part, and proper fractions without whole part.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::{FormatDirection, FormatOptions};
+/// use rustrings::fractions::{Fraction, FractionStyle, format_fraction};
+/// 
+/// let none = FormatOptions::new(FormatDirection::None, None);
+/// let five_halves = Fraction::new(5, 2).unwrap();
+/// assert_eq!(format_fraction(five_halves, FractionStyle::Ascii, none.clone()), "5/2");
+/// assert_eq!(format_fraction(five_halves, FractionStyle::Mixed, none.clone()), "2 1/2");
+/// assert_eq!(format_fraction(five_halves, FractionStyle::Unicode, none.clone()), "2 ½");
+/// assert_eq!(format_fraction(Fraction::new(3, 8).unwrap(), FractionStyle::Unicode, none.clone()), "⅜");
+/// assert_eq!(format_fraction(Fraction::new(-17, 32).unwrap(), FractionStyle::Unicode, none.clone()), "-¹⁷⁄₃₂");
+/// assert_eq!(format_fraction(Fraction::approximate(0.375, 64).unwrap(), FractionStyle::Mixed,
+///     FormatOptions::new(FormatDirection::Right(6), None)), "   3/8");
+/// ```
+pub fn format_fraction(
+	fraction: Fraction,
+	style: FractionStyle,
+	options: FormatOptions,
+) -> String {
+	let magnitude = fraction.numerator.unsigned_abs();
+	let (whole, numerator) = match style {
+		FractionStyle::Ascii if fraction.denominator != 1 => (0, magnitude),
+		_ => (magnitude / fraction.denominator, magnitude % fraction.denominator),
+	};
+	let mut result = if fraction.numerator < 0 {
+		"-".to_string()
+	} else {
+		String::new()
+	};
+	if whole != 0 || numerator == 0 {
+		result.push_str(&itoa_unsigned(whole, Base::new(10), Case::Lower));
+	}
+	if numerator != 0 {
+		if whole != 0 {
+			result.push(' ');
+		}
+		match style {
+			FractionStyle::Unicode => match VULGAR_FRACTIONS
+				.iter()
+				.find(|e| e.0 == numerator && e.1 == fraction.denominator)
+			{
+				Some(vulgar) => result.push(vulgar.2),
+				None => {
+					result.push_str(&digits_with(numerator, &SUPERSCRIPT_DIGITS));
+					result.push(FRACTION_SLASH);
+					result.push_str(&digits_with(fraction.denominator, &SUBSCRIPT_DIGITS));
+				},
+			},
+			_ => {
+				result.push_str(&itoa_unsigned(numerator, Base::new(10), Case::Lower));
+				result.push('/');
+				result.push_str(&itoa_unsigned(fraction.denominator, Base::new(10), Case::Lower));
+			},
+		}
+	}
+	format_text(&result, options)
+}
+
+/// Returned by `parse_fraction` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFractionKind {
+	/// Empty string.
+	Empty,
+	
+	/// Invalid character or missing part.
+	InvalidCharacter,
+	
+	/// Denominator is zero.
+	ZeroDenominator,
+	
+	/// Overflow.
+	Overflow,
+}
+
+/// Returns value of ASCII, superscript or subscript digit.
+fn digit_value(c: char) -> Option<usize> {
+	c
+		.to_digit(10)
+		.map(|e| e as usize)
+		.or_else(|| SUPERSCRIPT_DIGITS
+			.iter()
+			.position(|&e| e == c))
+		.or_else(|| SUBSCRIPT_DIGITS
+			.iter()
+			.position(|&e| e == c))
+}
+
+/// Parses leading digits of string, returning number (if any) and the rest.
+fn parse_digits(o: &str) -> Result<(Option<usize>, &str), ParseFractionKind> {
+	let mut result = None;
+	for (index, c) in o.char_indices() {
+		let Some(digit) = digit_value(c) else {
+			return Ok((result, &o[index..]));
+		};
+		result = Some(result
+			.unwrap_or(0usize)
+			.checked_mul(10)
+			.and_then(|e| e.checked_add(digit))
+			.ok_or(ParseFractionKind::Overflow)?);
+	}
+	Ok((result, ""))
+}
+
+/// Parses fractional part: vulgar fraction glyph or `numerator/denominator`, returning `(numerator, denominator)`.
+fn parse_fractional(o: &str) -> Result<(usize, usize), ParseFractionKind> {
+	let mut chars = o.chars();
+	if let Some(vulgar) = chars
+		.next()
+		.and_then(|c| VULGAR_FRACTIONS
+			.iter()
+			.find(|e| e.2 == c))
+	{
+		return if chars.as_str().is_empty() {
+			Ok((vulgar.0, vulgar.1))
+		} else {
+			Err(ParseFractionKind::InvalidCharacter)
+		};
+	}
+	let (numerator, rest) = parse_digits(o)?;
+	let rest = rest
+		.strip_prefix(['/', FRACTION_SLASH])
+		.ok_or(ParseFractionKind::InvalidCharacter)?;
+	let (denominator, rest) = parse_digits(rest)?;
+	match (numerator, denominator) {
+		(Some(numerator), Some(denominator)) if rest.is_empty() => Ok((numerator, denominator)),
+		_ => Err(ParseFractionKind::InvalidCharacter),
+	}
+}
+
+/// Parses fraction, mixed number or whole number written in any of `FractionStyle`s into reduced Fraction.
+/// Whole part of mixed number may be separated from fractional part by spaces.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::fractions::{Fraction, ParseFractionKind, parse_fraction};
+/// 
+/// assert_eq!(parse_fraction("3/8"), Ok(Fraction { numerator: 3, denominator: 8 }));
+/// assert_eq!(parse_fraction("2 1/2"), Ok(Fraction { numerator: 5, denominator: 2 }));
+/// assert_eq!(parse_fraction("2 ½"), Ok(Fraction { numerator: 5, denominator: 2 }));
+/// assert_eq!(parse_fraction("-¹⁷⁄₃₂"), Ok(Fraction { numerator: -17, denominator: 32 }));
+/// assert_eq!(parse_fraction("6/8"), Ok(Fraction { numerator: 3, denominator: 4 }));
+/// assert_eq!(parse_fraction("1/0"), Err(ParseFractionKind::ZeroDenominator));
+/// assert_eq!(parse_fraction("1/"), Err(ParseFractionKind::InvalidCharacter));
+/// ```
+pub fn parse_fraction(o: &str) -> Result<Fraction, ParseFractionKind> {
+	let o = o.trim();
+	if o.is_empty() {
+		return Err(ParseFractionKind::Empty);
+	}
+	let (negative, o) = match o.strip_prefix('-') {
+		Some(o) => (true, o),
+		None => (false, o),
+	};
+	let whole_end = o
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(o.len());
+	let (whole, numerator, denominator) = if o.is_empty() {
+		return Err(ParseFractionKind::InvalidCharacter);
+	} else if whole_end == o.len() {
+		(o, 0, 1)
+	} else if o[whole_end..].starts_with(['/', FRACTION_SLASH]) {
+		let (numerator, denominator) = parse_fractional(o)?;
+		("", numerator, denominator)
+	} else {
+		let (numerator, denominator) = parse_fractional(o[whole_end..].trim_start())?;
+		(&o[..whole_end], numerator, denominator)
+	};
+	if denominator == 0 {
+		return Err(ParseFractionKind::ZeroDenominator);
+	}
+	let (whole, _) = parse_digits(whole)?;
+	/* negative numerator may be isize::MIN, whose magnitude doesn't fit isize */
+	let limit = isize::MAX.unsigned_abs() + negative as usize;
+	let magnitude = whole
+		.unwrap_or(0)
+		.checked_mul(denominator)
+		.and_then(|e| e.checked_add(numerator))
+		.filter(|&e| e <= limit)
+		.ok_or(ParseFractionKind::Overflow)?;
+	Ok(Fraction::new(if negative {
+		0isize.wrapping_sub_unsigned(magnitude)
+	} else {
+		magnitude as isize
+	}, denominator).unwrap())
+}
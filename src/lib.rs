@@ -1,4 +1,9 @@
 pub mod converters;
+pub mod currency;
 pub mod format;
+pub mod fractions;
+pub mod ranges;
+pub mod units;
+pub mod words;
 
 mod tests;
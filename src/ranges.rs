@@ -0,0 +1,328 @@
+//! Numeric range lists.
+//! 
+//! This module contains parser and formatter for lists of inclusive ranges, such as `1-5,7,10-12`,
+//! used for page selection and job scheduling.
+
+use crate::converters::Base;
+use crate::converters::Case;
+use crate::converters::ParseIntKind;
+use crate::converters::atoi_unsigned;
+use crate::converters::itoa_unsigned;
+
+/// Inclusive range of numbers with step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberRange {
+	/// First number.
+	pub start: usize,
+	
+	/// Last number, `None` for open ranges (`5-`).
+	pub end: Option<usize>,
+	
+	/// Distance between adjacent numbers, at least 1.
+	pub step: usize,
+}
+
+impl NumberRange {
+	/// Constructs NumberRange.
+	pub fn new(
+		start: usize,
+		end: Option<usize>,
+		step: usize,
+	) -> NumberRange {
+		NumberRange {
+			start,
+			end,
+			step,
+		}
+	}
+	
+	/// Checks whether number is in range.
+	/// 
+	/// # Examples
+	/// 
+	/// ```
+	/// use rustrings::ranges::NumberRange;
+	/// 
+	/// assert!(NumberRange::new(1, Some(9), 2).contains(5));
+	/// assert!(!NumberRange::new(1, Some(9), 2).contains(4));
+	/// assert!(NumberRange::new(5, None, 1).contains(1000));
+	/// ```
+	pub fn contains(
+		&self,
+		number: usize,
+	) -> bool {
+		number >= self.start
+			&& self.end.is_none_or(|e| number <= e)
+			&& (number - self.start).is_multiple_of(self.step)
+	}
+}
+
+/// Range list parsing and formatting options.
+#[derive(Debug, Clone)]
+pub struct RangeOptions {
+	/// Base of numbers without prefix.
+	pub base: Base,
+	
+	/// Detect `0x`, `0o` and `0b` prefixes when parsing, and write them when formatting in base 16, 8 or 2.
+	pub prefixes: bool,
+	
+	/// Allow step syntax (`1-10:2`).
+	pub steps: bool,
+	
+	/// Allow open ranges (`5-`).
+	pub open: bool,
+	
+	/// Merge overlapping and adjacent ranges when parsing.
+	pub merge: bool,
+}
+
+impl RangeOptions {
+	/// Constructs RangeOptions.
+	pub fn new(
+		base: Base,
+		prefixes: bool,
+		steps: bool,
+		open: bool,
+		merge: bool,
+	) -> RangeOptions {
+		RangeOptions {
+			base,
+			prefixes,
+			steps,
+			open,
+			merge,
+		}
+	}
+}
+
+impl Default for RangeOptions {
+	fn default() -> RangeOptions {
+		RangeOptions::new(Base::new(10), true, true, true, true)
+	}
+}
+
+/// Returned by `parse_ranges` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseRangeKind {
+	/// Empty string or empty item.
+	Empty,
+	
+	/// Invalid number.
+	InvalidNumber(String),
+	
+	/// Range start is greater than end.
+	Reversed(String),
+	
+	/// Step is zero, or step syntax is disabled.
+	InvalidStep(String),
+	
+	/// Open range, while open ranges are disabled.
+	OpenRange(String),
+	
+	/// Overflow.
+	Overflow,
+}
+
+/// Parses number in base from options, or in base of its prefix.
+fn parse_number(
+	o: &str,
+	options: &RangeOptions,
+) -> Result<usize, ParseRangeKind> {
+	let o = o.trim();
+	let digits = &Case::Upper.alphabet()[..options.base.get_value() as usize];
+	/* prefix letter which is a digit of base (`0b` in base 16) is not a prefix */
+	let prefix = o
+		.get(..2)
+		.map(|e| e.to_ascii_uppercase())
+		.filter(|e| !e
+			.chars()
+			.last()
+			.is_some_and(|c| digits.contains(&c)));
+	let (digits, base) = match prefix.as_deref() {
+		Some("0X") if options.prefixes => (&o[2..], Base::new(16)),
+		Some("0O") if options.prefixes => (&o[2..], Base::new(8)),
+		Some("0B") if options.prefixes => (&o[2..], Base::new(2)),
+		_ => (o, options.base),
+	};
+	let alphabet = &Case::Upper.alphabet()[..base.get_value() as usize];
+	if digits.is_empty() || !digits
+		.chars()
+		.all(|c| alphabet.contains(&c.to_ascii_uppercase()))
+	{
+		return Err(ParseRangeKind::InvalidNumber(o.to_string()));
+	}
+	atoi_unsigned(digits, base, false).map_err(|e| match e {
+		ParseIntKind::Overflow => ParseRangeKind::Overflow,
+		_ => ParseRangeKind::InvalidNumber(o.to_string()),
+	})
+}
+
+/// Parses one item of range list.
+fn parse_range(
+	o: &str,
+	options: &RangeOptions,
+) -> Result<NumberRange, ParseRangeKind> {
+	let (range, step) = match o.split_once(':') {
+		Some((range, step)) if options.steps => match parse_number(step, options)? {
+			0 => return Err(ParseRangeKind::InvalidStep(o.to_string())),
+			step => (range, step),
+		},
+		Some(_) => return Err(ParseRangeKind::InvalidStep(o.to_string())),
+		None => (o, 1),
+	};
+	let Some((start, end)) = range.split_once('-') else {
+		let start = parse_number(range, options)?;
+		return Ok(NumberRange::new(start, Some(start), 1));
+	};
+	let start = parse_number(start, options)?;
+	let end = if end.trim().is_empty() {
+		if !options.open {
+			return Err(ParseRangeKind::OpenRange(o.to_string()));
+		}
+		None
+	} else {
+		let end = parse_number(end, options)?;
+		if end < start {
+			return Err(ParseRangeKind::Reversed(o.to_string()));
+		}
+		/* last number actually in range */
+		Some(end - (end - start) % step)
+	};
+	Ok(NumberRange::new(start, end, if end == Some(start) {
+		1
+	} else {
+		step
+	}))
+}
+
+/// Parses comma-separated list of numbers and inclusive ranges into list sorted by start.
+/// Items may be numbers (`7`), ranges (`1-5`), ranges with step (`1-10:2`) and open ranges (`5-`).
+/// With merging, overlapping and adjacent ranges without step are joined, and ranges covered by others are dropped.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::ranges::{NumberRange, RangeOptions, parse_ranges};
+/// 
+/// assert_eq!(parse_ranges("1-5,7,0x10-0x12", &RangeOptions::default()), Ok(vec![
+///     NumberRange::new(1, Some(5), 1),
+///     NumberRange::new(7, Some(7), 1),
+///     NumberRange::new(16, Some(18), 1),
+/// ]));
+/// assert_eq!(parse_ranges("8-, 1-10:2, 3-6, 4", &RangeOptions::default()), Ok(vec![
+///     NumberRange::new(1, Some(9), 2),
+///     NumberRange::new(3, Some(6), 1),
+///     NumberRange::new(8, None, 1),
+/// ]));
+/// ```
+pub fn parse_ranges(
+	o: &str,
+	options: &RangeOptions,
+) -> Result<Vec<NumberRange>, ParseRangeKind> {
+	if o.trim().is_empty() {
+		return Err(ParseRangeKind::Empty);
+	}
+	let mut ranges = o
+		.split(',')
+		.map(|e| if e.trim().is_empty() {
+			Err(ParseRangeKind::Empty)
+		} else {
+			parse_range(e.trim(), options)
+		})
+		.collect::<Result<Vec<NumberRange>, ParseRangeKind>>()?;
+	ranges.sort_by_key(|e| (e.start, e.end.unwrap_or(usize::MAX), e.step));
+	ranges.dedup();
+	if !options.merge {
+		return Ok(ranges);
+	}
+	let mut result: Vec<NumberRange> = Vec::new();
+	for range in ranges {
+		if let Some(last) = result
+			.iter_mut()
+			.rev()
+			.find(|e| e.step == 1)
+		{
+			let last_end = last.end.unwrap_or(usize::MAX);
+			let end = range.end.unwrap_or(usize::MAX);
+			if end <= last_end {
+				/* covered */
+				continue;
+			}
+			if range.step == 1 && range.start <= last_end.saturating_add(1) {
+				last.end = range.end;
+				continue;
+			}
+		}
+		result.push(range);
+	}
+	result.sort_by_key(|e| (e.start, e.end.unwrap_or(usize::MAX), e.step));
+	Ok(result)
+}
+
+/// Writes number in base from options, with prefix if enabled.
+fn format_number(
+	number: usize,
+	options: &RangeOptions,
+) -> String {
+	let prefix = match options.base.get_value() {
+		16 if options.prefixes => "0x",
+		8 if options.prefixes => "0o",
+		2 if options.prefixes => "0b",
+		_ => "",
+	};
+	format!("{}{}", prefix, itoa_unsigned(number, options.base, Case::Lower))
+}
+
+/// Compresses set of numbers into range list, inverse of `parse_ranges`.
+/// Runs of three or more consecutive numbers are written as ranges, and with step syntax enabled,
+/// runs of numbers with equal distance are written as ranges with step when it makes string shorter.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::converters::Base;
+/// use rustrings::ranges::{RangeOptions, format_ranges};
+/// 
+/// assert_eq!(format_ranges(&[1, 2, 3, 4, 5, 7, 10, 11, 12], &RangeOptions::default()), "1-5,7,10-12");
+/// assert_eq!(format_ranges(&[10, 20, 30, 40, 50], &RangeOptions::default()), "10-50:10");
+/// assert_eq!(format_ranges(&[16, 17, 18], &RangeOptions::new(Base::new(16), true, false, false, true)), "0x10-0x12");
+/// ```
+pub fn format_ranges(
+	numbers: &[usize],
+	options: &RangeOptions,
+) -> String {
+	let mut numbers = numbers.to_vec();
+	numbers.sort_unstable();
+	numbers.dedup();
+	let mut items = Vec::new();
+	let mut index = 0;
+	while index < numbers.len() {
+		let start = numbers[index];
+		let mut last = index;
+		if index + 1 < numbers.len() {
+			let step = numbers[index + 1] - start;
+			while last + 1 < numbers.len() && numbers[last + 1] - numbers[last] == step {
+				last += 1;
+			}
+			if last - index >= 2 && (step == 1 || options.steps) {
+				let mut range = format!("{}-{}", format_number(start, options), format_number(numbers[last], options));
+				if step != 1 {
+					range.push(':');
+					range.push_str(&format_number(step, options));
+				}
+				let listed = numbers[index..=last]
+					.iter()
+					.map(|e| format_number(*e, options).len() + 1)
+					.sum::<usize>() - 1;
+				if range.len() < listed {
+					items.push(range);
+					index = last + 1;
+					continue;
+				}
+			}
+		}
+		items.push(format_number(start, options));
+		index += 1;
+	}
+	items.join(",")
+}
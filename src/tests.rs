@@ -1,7 +1,17 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
+#[allow(clippy::unnecessary_to_owned)]
 mod tests {
+	use std::sync::Arc;
+	use std::time::Duration;
+
 	use crate::converters::*;
+	use crate::currency::*;
 	use crate::format::*;
+	use crate::fractions::*;
+	use crate::ranges::*;
+	use crate::units::*;
+	use crate::words::*;
 
 	#[test]
 	fn test_itoa_hex_speak() {
@@ -499,4 +509,1533 @@ mod tests {
 			]
 		), "14%10=4");
 	}
+
+	#[test]
+	fn test_roman() {
+		for number in 1..=3999 {
+			let upper = itoa_roman(number, RomanOptions::default(), Case::Upper).unwrap();
+			let lower = itoa_roman(number, RomanOptions::default(), Case::Lower).unwrap();
+			assert_eq!(atoi_roman(&upper), Ok(number));
+			assert_eq!(atoi_roman(&lower), Ok(number));
+		}
+		assert_eq!(itoa_roman(3999, RomanOptions::default(), Case::Upper), Some("MMMCMXCIX".to_string()));
+		assert_eq!(itoa_roman(444, RomanOptions::new(RomanNotation::Additive, false, false), Case::Upper), Some("CCCCXXXXIIII".to_string()));
+		assert_eq!(itoa_roman(4, RomanOptions::new(RomanNotation::Additive, true, false), Case::Upper), Some("ⅠⅠⅠⅠ".to_string()));
+		assert_eq!(itoa_roman(2024, RomanOptions::new(RomanNotation::Standard, true, false), Case::Upper), Some("ⅯⅯⅩⅩⅠⅤ".to_string()));
+		assert_eq!(itoa_roman(3_999_999, RomanOptions::new(RomanNotation::Standard, false, true), Case::Upper),
+			Some("M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX".to_string()));
+		assert_eq!(itoa_roman(4_000_000, RomanOptions::new(RomanNotation::Standard, false, true), Case::Upper), None);
+		assert_eq!(atoi_roman("ⅯⅯⅩⅩⅣ"), Ok(2024));
+		assert_eq!(atoi_roman("M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX"), Ok(3_999_999));
+		assert_eq!(atoi_roman("I\u{305}I\u{305}I\u{305}"), Err(ParseRomanKind::NonCanonical));
+		assert_eq!(atoi_roman("\u{305}I"), Err(ParseRomanKind::InvalidCharacter));
+		assert_eq!(atoi_roman("VX"), Err(ParseRomanKind::NonCanonical));
+		assert_eq!(atoi_roman("IC"), Err(ParseRomanKind::NonCanonical));
+		assert_eq!(atoi_roman("MMMM"), Err(ParseRomanKind::NonCanonical));
+		assert_eq!(atoi_roman("XIIII"), Err(ParseRomanKind::NonCanonical));
+		assert_eq!(atoi_roman(" X"), Err(ParseRomanKind::InvalidCharacter));
+	}
+
+	#[test]
+	fn test_words_english() {
+		assert_eq!(cardinal(0, &English), "zero");
+		assert_eq!(cardinal(13, &English), "thirteen");
+		assert_eq!(cardinal(40, &English), "forty");
+		assert_eq!(cardinal(100, &English), "one hundred");
+		assert_eq!(cardinal(1_000_001, &English), "one million one");
+		assert_eq!(cardinal(i128::MAX, &English), "one hundred seventy undecillion one hundred forty-one decillion one hundred eighty-three nonillion four hundred sixty octillion four hundred sixty-nine septillion two hundred thirty-one sextillion seven hundred thirty-one quintillion six hundred eighty-seven quadrillion three hundred three trillion seven hundred fifteen billion eight hundred eighty-four million one hundred five thousand seven hundred twenty-seven");
+		assert_eq!(cardinal(i128::MIN, &English), "minus one hundred seventy undecillion one hundred forty-one decillion one hundred eighty-three nonillion four hundred sixty octillion four hundred sixty-nine septillion two hundred thirty-one sextillion seven hundred thirty-one quintillion six hundred eighty-seven quadrillion three hundred three trillion seven hundred fifteen billion eight hundred eighty-four million one hundred five thousand seven hundred twenty-eight");
+		assert_eq!(ordinal(0, &English), "zeroth");
+		assert_eq!(ordinal(2, &English), "second");
+		assert_eq!(ordinal(12, &English), "twelfth");
+		assert_eq!(ordinal(40, &English), "fortieth");
+		assert_eq!(ordinal(103, &English), "one hundred third");
+		assert_eq!(ordinal(1000, &English), "one thousandth");
+	}
+
+	#[test]
+	fn test_words_french() {
+		assert_eq!(cardinal(0, &French), "zéro");
+		assert_eq!(cardinal(17, &French), "dix-sept");
+		assert_eq!(cardinal(21, &French), "vingt et un");
+		assert_eq!(cardinal(71, &French), "soixante et onze");
+		assert_eq!(cardinal(77, &French), "soixante-dix-sept");
+		assert_eq!(cardinal(81, &French), "quatre-vingt-un");
+		assert_eq!(cardinal(99, &French), "quatre-vingt-dix-neuf");
+		assert_eq!(cardinal(200, &French), "deux cents");
+		assert_eq!(cardinal(201, &French), "deux cent un");
+		assert_eq!(cardinal(1000, &French), "mille");
+		assert_eq!(cardinal(80_000, &French), "quatre-vingt mille");
+		assert_eq!(cardinal(200_000_000, &French), "deux cents millions");
+		assert_eq!(cardinal(1_000_000_000, &French), "un milliard");
+		assert_eq!(cardinal(-5, &French), "moins cinq");
+		assert_eq!(ordinal(2, &French), "deuxième");
+		assert_eq!(ordinal(3, &French), "troisième");
+		assert_eq!(ordinal(5, &French), "cinquième");
+		assert_eq!(ordinal(9, &French), "neuvième");
+		assert_eq!(ordinal(21, &French), "vingt et unième");
+		assert_eq!(ordinal(80, &French), "quatre-vingtième");
+		assert_eq!(ordinal(200, &French), "deux centième");
+		assert_eq!(ordinal(1000, &French), "millième");
+		assert_eq!(ordinal(2_000_000, &French), "deux millionième");
+	}
+
+	#[test]
+	fn test_words_german() {
+		assert_eq!(cardinal(0, &German), "null");
+		assert_eq!(cardinal(1, &German), "eins");
+		assert_eq!(cardinal(17, &German), "siebzehn");
+		assert_eq!(cardinal(101, &German), "einhunderteins");
+		assert_eq!(cardinal(1234, &German), "eintausendzweihundertvierunddreißig");
+		assert_eq!(cardinal(1_000_000, &German), "eine Million");
+		assert_eq!(cardinal(2_500_001, &German), "zwei Millionen fünfhunderttausendeins");
+		assert_eq!(cardinal(-3_000_000_000, &German), "minus drei Milliarden");
+		assert_eq!(ordinal(0, &German), "nullte");
+		assert_eq!(ordinal(1, &German), "erste");
+		assert_eq!(ordinal(7, &German), "siebte");
+		assert_eq!(ordinal(19, &German), "neunzehnte");
+		assert_eq!(ordinal(20, &German), "zwanzigste");
+		assert_eq!(ordinal(101, &German), "einhunderterste");
+		assert_eq!(ordinal(1000, &German), "eintausendste");
+		assert_eq!(ordinal(1_000_000, &German), "einmillionste");
+		assert_eq!(ordinal(2_001_000_000, &German), "zweimilliardeneinmillionste");
+	}
+
+	#[test]
+	fn test_ordinal_suffix() {
+		assert_eq!(itoa_ordinal(0, Locale::English), "0th");
+		assert_eq!(itoa_ordinal(11, Locale::English), "11th");
+		assert_eq!(itoa_ordinal(12, Locale::English), "12th");
+		assert_eq!(itoa_ordinal(13, Locale::English), "13th");
+		assert_eq!(itoa_ordinal(101, Locale::English), "101st");
+		assert_eq!(itoa_ordinal(111, Locale::English), "111th");
+		assert_eq!(itoa_ordinal(1002, Locale::English), "1002nd");
+		assert_eq!(itoa_ordinal(101, Locale::French), "101e");
+		assert_eq!(itoa_ordinal(-1, Locale::French), "-1er");
+		assert_eq!(itoa_ordinal(3, Locale::German), "3.");
+		assert_eq!(itoa_ordinal(21, Locale::Spanish), "21.º");
+
+		assert_eq!(c_format("%~hhu %~hd %~ld %~llu".to_string(),
+			vec![
+				CFormatArgument::UInt8(1),
+				CFormatArgument::Int16(-2),
+				CFormatArgument::IntSize(3),
+				CFormatArgument::UInt64(11),
+			]
+		), "1st -2nd 3rd 11th");
+		assert_eq!(c_format("%~10d|%~x".to_string(),
+			vec![
+				CFormatArgument::Int32(111),
+				CFormatArgument::UInt32(1),
+			]
+		), "     111th|1");
+	}
+
+	#[test]
+	fn test_bytes() {
+		let none = FormatOptions::new(FormatDirection::None, None);
+		assert_eq!(format_bytes(0, ByteUnits::Decimal, 2, none.clone()), "0 B");
+		assert_eq!(format_bytes(999, ByteUnits::Decimal, 2, none.clone()), "999 B");
+		assert_eq!(format_bytes(1000, ByteUnits::Decimal, 2, none.clone()), "1.00 kB");
+		assert_eq!(format_bytes(1000, ByteUnits::Binary, 2, none.clone()), "1000 B");
+		assert_eq!(format_bytes(1024, ByteUnits::Binary, 2, none.clone()), "1.00 KiB");
+		assert_eq!(format_bytes(1_048_575, ByteUnits::Binary, 1, none.clone()), "1.0 MiB");
+		assert_eq!(format_bytes(u64::MAX, ByteUnits::Binary, 2, none.clone()), "16.00 EiB");
+		assert_eq!(format_bytes(u64::MAX, ByteUnits::Decimal, 1, none.clone()), "18.4 EB");
+		assert_eq!(format_bytes(1536, ByteUnits::Binary, 1, FormatOptions::new(FormatDirection::Left(10), Some('.'))), "1.5 KiB...");
+
+		assert_eq!(parse_bytes("0"), Ok(0));
+		assert_eq!(parse_bytes("  42 B "), Ok(42));
+		assert_eq!(parse_bytes("1kb"), Ok(1000));
+		assert_eq!(parse_bytes("1KB"), Ok(1000));
+		assert_eq!(parse_bytes("1 KiB"), Ok(1024));
+		assert_eq!(parse_bytes("1ki"), Ok(1024));
+		assert_eq!(parse_bytes("0.5"), Ok(1));
+		assert_eq!(parse_bytes("1.0001 kB"), Ok(1000));
+		assert_eq!(parse_bytes(".5 MiB"), Ok(524288));
+		assert_eq!(parse_bytes("2.25 TB"), Ok(2_250_000_000_000));
+		assert_eq!(parse_bytes("16 EiB"), Err(ParseBytesKind::Overflow));
+		assert_eq!(parse_bytes("15.99 EiB"), Ok(18_435_214_858_663_483_146));
+		assert_eq!(parse_bytes("1.2.3 kB"), Err(ParseBytesKind::InvalidNumber));
+		assert_eq!(parse_bytes("."), Err(ParseBytesKind::InvalidNumber));
+		assert_eq!(parse_bytes("-1 kB"), Err(ParseBytesKind::InvalidNumber));
+		assert_eq!(parse_bytes("1 ib"), Err(ParseBytesKind::InvalidUnit("ib".to_string())));
+		assert_eq!(parse_bytes("1 kilobyte"), Err(ParseBytesKind::InvalidUnit("kilobyte".to_string())));
+		for bytes in [1u64, 1023, 1024, 1_500_000, 1_610_612_736] {
+			assert!(parse_bytes(&format_bytes(bytes, ByteUnits::Binary, 0, none.clone())).is_ok());
+		}
+	}
+
+	#[test]
+	fn test_atof() {
+		assert_eq!(atof("0"), Ok(0.0));
+		assert_eq!(atof("+2.5"), Ok(2.5));
+		assert_eq!(atof("1."), Ok(1.0));
+		assert_eq!(atof("1e-3s"), Ok(0.001));
+		assert_eq!(atof("1.5.5"), Ok(1.5));
+		assert_eq!(atof("Infinity"), Ok(f64::INFINITY));
+		assert!(atof("NaN").unwrap().is_nan());
+		assert_eq!(atof("-"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("."), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("e5"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("ab€"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("-∞x"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("in€"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("é"), Err(ParseFloatKind::InvalidCharacter));
+		assert_eq!(atof("inf€"), Ok(f64::INFINITY));
+		assert_eq!(atof("2½"), Ok(2.0));
+	}
+
+	#[test]
+	fn test_duration() {
+		assert_eq!(format_duration(Duration::ZERO, DurationStyle::Compact), "0s");
+		assert_eq!(format_duration(Duration::ZERO, DurationStyle::Iso8601), "PT0S");
+		assert_eq!(format_duration(Duration::from_secs(86400), DurationStyle::Iso8601), "P1D");
+		assert_eq!(format_duration(Duration::from_secs(90061), DurationStyle::Iso8601), "P1DT1H1M1S");
+		assert_eq!(format_duration(Duration::from_secs(90061), DurationStyle::Compact), "1d1h1m1s");
+		assert_eq!(format_duration(Duration::from_secs(90061), DurationStyle::Verbose), "1 day, 1 hour, 1 minute, 1 second");
+		assert_eq!(format_duration(Duration::new(0, 1_002_003), DurationStyle::Compact), "1ms2µs3ns");
+		assert_eq!(format_duration(Duration::new(0, 1_002_003), DurationStyle::Iso8601), "PT0.001002003S");
+		assert_eq!(format_duration(Duration::new(7200, 2), DurationStyle::Verbose), "2 hours, 2 nanoseconds");
+		assert_eq!(format_duration(Duration::new(7200, 2), DurationStyle::Iso8601), "PT2H0.000000002S");
+
+		assert_eq!(parse_duration("1d1h1m1s"), Ok(Duration::from_secs(90061)));
+		assert_eq!(parse_duration("1 day, 1 hour, 1 minute and 1 second"), Ok(Duration::from_secs(90061)));
+		assert_eq!(parse_duration("2 Hours 15 MINS"), Ok(Duration::from_secs(8100)));
+		assert_eq!(parse_duration("1ms2µs3ns"), Ok(Duration::new(0, 1_002_003)));
+		assert_eq!(parse_duration("250us"), Ok(Duration::from_micros(250)));
+		assert_eq!(parse_duration("1.5h"), Ok(Duration::from_secs(5400)));
+		assert_eq!(parse_duration("2w"), Ok(Duration::from_secs(1_209_600)));
+		assert_eq!(parse_duration("P1DT1H1M1S"), Ok(Duration::from_secs(90061)));
+		assert_eq!(parse_duration("P2W"), Ok(Duration::from_secs(1_209_600)));
+		assert_eq!(parse_duration("PT0,5S"), Ok(Duration::from_millis(500)));
+		assert_eq!(parse_duration("PT2H0.000000002S"), Ok(Duration::new(7200, 2)));
+		assert_eq!(parse_duration(""), Err(ParseDurationKind::Empty));
+		assert_eq!(parse_duration("P"), Err(ParseDurationKind::InvalidNumber));
+		assert_eq!(parse_duration("P1Y"), Err(ParseDurationKind::InvalidUnit("Y".to_string())));
+		assert_eq!(parse_duration("PT1S1M"), Err(ParseDurationKind::InvalidUnit("M".to_string())));
+		assert_eq!(parse_duration("P1H"), Err(ParseDurationKind::InvalidUnit("H".to_string())));
+		assert_eq!(parse_duration("PT5"), Err(ParseDurationKind::MissingUnit));
+		assert_eq!(parse_duration("h"), Err(ParseDurationKind::InvalidNumber));
+		assert_eq!(parse_duration("1.2.3s"), Err(ParseDurationKind::InvalidNumber));
+		assert_eq!(parse_duration("99999999999999999999999d"), Err(ParseDurationKind::Overflow));
+		for seconds in [0u64, 1, 59, 61, 3599, 3601, 86399, 1_000_000] {
+			let duration = Duration::new(seconds, 123_456_789);
+			assert_eq!(parse_duration(&format_duration(duration, DurationStyle::Compact)), Ok(duration));
+			assert_eq!(parse_duration(&format_duration(duration, DurationStyle::Verbose)), Ok(duration));
+			assert_eq!(parse_duration(&format_duration(duration, DurationStyle::Iso8601)), Ok(duration));
+		}
+	}
+
+	#[test]
+	fn test_si() {
+		let none = FormatOptions::new(FormatDirection::None, None);
+		assert_eq!(format_si(0.0, "V", 3, SiNotation::Prefix, none.clone()), "0.00 V");
+		assert_eq!(format_si(1.0, "", 1, SiNotation::Prefix, none.clone()), "1");
+		assert_eq!(format_si(999.9, "", 3, SiNotation::Prefix, none.clone()), "1.00 k");
+		assert_eq!(format_si(0.00005, "s", 1, SiNotation::Prefix, none.clone()), "50 µs");
+		assert_eq!(format_si(123456.0, "", 2, SiNotation::Engineering, none.clone()), "120e3");
+		assert_eq!(format_si(1.5, "", 2, SiNotation::Engineering, none.clone()), "1.5e0");
+		assert_eq!(format_si(1e33, "g", 2, SiNotation::Prefix, none.clone()), "1.0e33 g");
+		assert_eq!(format_si(1e-31, "", 1, SiNotation::Prefix, none.clone()), "100e-33");
+		assert_eq!(format_si(f64::INFINITY, "W", 3, SiNotation::Prefix, none.clone()), "inf W");
+		assert_eq!(format_si(0.0047, "F", 2, SiNotation::Prefix, FormatOptions::new(FormatDirection::Left(8), Some('.'))), "4.7 mF..");
+
+		assert_eq!(parse_si("0", ""), Ok(0.0));
+		assert_eq!(parse_si("-2.5m", ""), Ok(-0.0025));
+		assert_eq!(parse_si("470n", ""), Ok(0.00000047));
+		assert_eq!(parse_si("10u", "F"), Ok(0.00001));
+		assert_eq!(parse_si("10uF", "F"), Ok(0.00001));
+		assert_eq!(parse_si("1.5e3", ""), Ok(1500.0));
+		assert_eq!(parse_si("2K2", ""), Ok(2200.0));
+		assert_eq!(parse_si("1G", ""), Ok(1e9));
+		assert_eq!(parse_si("", ""), Err(ParseSiKind::Empty));
+		assert_eq!(parse_si("k", ""), Err(ParseSiKind::InvalidNumber));
+		assert_eq!(parse_si("1.2.3", ""), Err(ParseSiKind::InvalidNumber));
+		assert_eq!(parse_si("1e3k", ""), Err(ParseSiKind::InvalidPrefix("k".to_string())));
+		assert_eq!(parse_si("4.7k7", ""), Err(ParseSiKind::InvalidPrefix("k7".to_string())));
+		assert_eq!(parse_si("4kk", ""), Err(ParseSiKind::InvalidPrefix("kk".to_string())));
+		for value in [0.000047, 4700.0, 2.2e6, 33.0, 1e-12] {
+			assert_eq!(parse_si(&format_si(value, "", 2, SiNotation::Prefix, none.clone()), ""), Ok(value));
+			assert_eq!(parse_si(&format_si(value, "", 2, SiNotation::Engineering, none.clone()), ""), Ok(value));
+		}
+	}
+
+	#[test]
+	fn test_decimal() {
+		assert_eq!(parse_decimal("0"), Ok(Decimal::new(0, 0)));
+		assert_eq!(parse_decimal("+.50"), Ok(Decimal::new(50, 2)));
+		assert_eq!(parse_decimal("10."), Ok(Decimal::new(10, 0)));
+		assert_eq!(parse_decimal("-170141183460469231731687303715884105728"), Ok(Decimal::new(i128::MIN, 0)));
+		assert_eq!(parse_decimal("170141183460469231731687303715884105728"), Err(ParseDecimalKind::Overflow));
+		assert_eq!(parse_decimal(""), Err(ParseDecimalKind::Empty));
+		assert_eq!(parse_decimal("-"), Err(ParseDecimalKind::InvalidCharacter));
+		assert_eq!(parse_decimal("1,5"), Err(ParseDecimalKind::InvalidCharacter));
+		assert_eq!(parse_decimal("1 5"), Err(ParseDecimalKind::InvalidCharacter));
+
+		assert_eq!(Decimal::new(25, 1).rescale(0, RoundingMode::HalfEven), Some(Decimal::new(2, 0)));
+		assert_eq!(Decimal::new(35, 1).rescale(0, RoundingMode::HalfEven), Some(Decimal::new(4, 0)));
+		assert_eq!(Decimal::new(-25, 1).rescale(0, RoundingMode::HalfEven), Some(Decimal::new(-2, 0)));
+		assert_eq!(Decimal::new(25, 1).rescale(0, RoundingMode::HalfUp), Some(Decimal::new(3, 0)));
+		assert_eq!(Decimal::new(-25, 1).rescale(0, RoundingMode::HalfUp), Some(Decimal::new(-3, 0)));
+		assert_eq!(Decimal::new(-29, 1).rescale(0, RoundingMode::Truncate), Some(Decimal::new(-2, 0)));
+		assert_eq!(Decimal::new(251, 2).rescale(1, RoundingMode::HalfEven), Some(Decimal::new(25, 1)));
+		assert_eq!(Decimal::new(1, 0).rescale(60, RoundingMode::HalfEven), None);
+		assert_eq!(Decimal::new(i128::MAX, 60).rescale(0, RoundingMode::HalfEven), Some(Decimal::new(0, 0)));
+
+		let amount = parse_decimal("-1234567.895").unwrap();
+		assert_eq!(format_decimal(amount, 2, RoundingMode::HalfEven, Locale::English, true), Some("-1,234,567.90".to_string()));
+		assert_eq!(format_decimal(amount, 2, RoundingMode::Truncate, Locale::French, true), Some("-1 234 567,89".to_string()));
+		assert_eq!(format_decimal(amount, 5, RoundingMode::Truncate, Locale::Spanish, false), Some("-1234567,89500".to_string()));
+		assert_eq!(format_decimal(Decimal::new(5, 3), 2, RoundingMode::HalfEven, Locale::English, true), Some("0.00".to_string()));
+		assert_eq!(format_decimal(Decimal::new(-5, 3), 2, RoundingMode::HalfUp, Locale::English, true), Some("-0.01".to_string()));
+		assert_eq!(format_decimal(Decimal::new(-4, 3), 2, RoundingMode::HalfUp, Locale::English, true), Some("0.00".to_string()));
+		assert_eq!(format_decimal(Decimal::new(7, 0), 3, RoundingMode::HalfUp, Locale::German, true), Some("7,000".to_string()));
+	}
+
+	#[test]
+	fn test_currency() {
+		let usd = Currency::from_code("USD").unwrap();
+		let eur = Currency::from_code("EUR").unwrap();
+		let jpy = Currency::from_code("JPY").unwrap();
+		let kwd = Currency::from_code("KWD").unwrap();
+		let english = CurrencyOptions::default();
+		let accounting = CurrencyOptions::new(Locale::English, CurrencyDisplay::Symbol, true, RoundingMode::HalfEven);
+		let german = CurrencyOptions::new(Locale::German, CurrencyDisplay::Symbol, false, RoundingMode::HalfUp);
+
+		assert_eq!(format_currency(Decimal::new(-123456, 2), &usd, english.clone(), FormatDirection::None), Some("-$1,234.56".to_string()));
+		assert_eq!(format_currency(Decimal::new(5, 1), &usd, english.clone(), FormatDirection::None), Some("$0.50".to_string()));
+		assert_eq!(format_currency(Decimal::new(12345, 1), &jpy, english.clone(), FormatDirection::None), Some("¥1,234".to_string()));
+		assert_eq!(format_currency(Decimal::new(12345, 1), &kwd, english.clone(), FormatDirection::None), Some("KD 1,234.500".to_string()));
+		assert_eq!(format_currency(Decimal::new(1234565, 3), &eur, german.clone(), FormatDirection::None), Some("1.234,57 €".to_string()));
+		assert_eq!(format_currency(Decimal::new(-100, 0), &eur,
+			CurrencyOptions::new(Locale::French, CurrencyDisplay::Code, true, RoundingMode::HalfEven),
+			FormatDirection::None), Some("(100,00 EUR)".to_string()));
+
+		/* amounts in a column line up on the decimal point */
+		let column = [Decimal::new(123456, 2), Decimal::new(-5, 0), Decimal::new(99999999, 2)]
+			.iter()
+			.map(|e| format_currency(*e, &usd, accounting.clone(), FormatDirection::Right(14)).unwrap())
+			.collect::<Vec<String>>();
+		assert_eq!(column, vec![
+			"    $1,234.56 ".to_string(),
+			"       ($5.00)".to_string(),
+			"  $999,999.99 ".to_string(),
+		]);
+		let column = [Decimal::new(123456, 2), Decimal::new(-5, 0)]
+			.iter()
+			.map(|e| format_currency(*e, &eur, german.clone(), FormatDirection::Right(12)).unwrap())
+			.collect::<Vec<String>>();
+		assert_eq!(column, vec![
+			"  1.234,56 €".to_string(),
+			"     -5,00 €".to_string(),
+		]);
+	}
+
+	#[test]
+	fn test_significant() {
+		let positional = SignificantNotation::Positional;
+		let scientific = SignificantNotation::Scientific;
+		assert_eq!(itoa_significant(0, 3, RoundingMode::HalfEven, positional), "0.00");
+		assert_eq!(itoa_significant(7, 1, RoundingMode::HalfEven, scientific), "7e0");
+		assert_eq!(itoa_significant(99950, 3, RoundingMode::HalfEven, positional), "100000");
+		assert_eq!(itoa_significant(99950, 3, RoundingMode::HalfEven, scientific), "1.00e5");
+		assert_eq!(itoa_significant(12250, 3, RoundingMode::HalfEven, positional), "12200");
+		assert_eq!(itoa_significant(12250, 3, RoundingMode::HalfUp, positional), "12300");
+		assert_eq!(itoa_significant(-12250, 3, RoundingMode::HalfUp, positional), "-12300");
+		assert_eq!(itoa_significant(-12299, 3, RoundingMode::Ceiling, positional), "-12200");
+		assert_eq!(itoa_significant(12299, 3, RoundingMode::Floor, positional), "12200");
+		assert_eq!(itoa_significant(12299, 3, RoundingMode::Truncate, positional), "12200");
+		assert_eq!(itoa_significant(12, 5, RoundingMode::HalfEven, positional), "12.000");
+		assert_eq!(itoa_significant(i128::MIN, 2, RoundingMode::HalfEven, scientific), "-1.7e38");
+
+		assert_eq!(ftoa_significant(0.0, 2, RoundingMode::HalfEven, positional), "0.0");
+		assert_eq!(ftoa_significant(-0.0, 2, RoundingMode::HalfEven, positional), "0.0");
+		assert_eq!(ftoa_significant(0.000123456, 3, RoundingMode::HalfEven, positional), "0.000123");
+		assert_eq!(ftoa_significant(123.456, 4, RoundingMode::HalfEven, positional), "123.5");
+		assert_eq!(ftoa_significant(123.456, 4, RoundingMode::Floor, positional), "123.4");
+		assert_eq!(ftoa_significant(-123.456, 4, RoundingMode::Floor, positional), "-123.5");
+		assert_eq!(ftoa_significant(-123.456, 4, RoundingMode::Ceiling, positional), "-123.4");
+		assert_eq!(ftoa_significant(1.005, 3, RoundingMode::HalfUp, positional), "1.01");
+		assert_eq!(ftoa_significant(6.02214076e23, 3, RoundingMode::HalfEven, scientific), "6.02e23");
+		assert_eq!(ftoa_significant(9.99, 2, RoundingMode::HalfEven, positional), "10");
+		assert_eq!(ftoa_significant(f64::NAN, 3, RoundingMode::HalfEven, positional), "nan");
+		assert_eq!(ftoa_significant(f64::NEG_INFINITY, 3, RoundingMode::HalfEven, scientific), "-inf");
+
+		assert_eq!(Decimal::new(12341, 3).rescale(2, RoundingMode::Ceiling), Some(Decimal::new(1235, 2)));
+		assert_eq!(Decimal::new(-12341, 3).rescale(2, RoundingMode::Ceiling), Some(Decimal::new(-1234, 2)));
+		assert_eq!(Decimal::new(12349, 3).rescale(2, RoundingMode::Floor), Some(Decimal::new(1234, 2)));
+		assert_eq!(Decimal::new(-12341, 3).rescale(2, RoundingMode::Floor), Some(Decimal::new(-1235, 2)));
+		assert_eq!(Decimal::new(1000, 3).rescale(0, RoundingMode::Ceiling), Some(Decimal::new(1, 0)));
+	}
+	#[test]
+	fn test_fractions() {
+		let none = FormatOptions::new(FormatDirection::None, None);
+		assert_eq!(Fraction::approximate(0.0, 10), Fraction::new(0, 1));
+		assert_eq!(Fraction::approximate(0.999, 10), Fraction::new(1, 1));
+		assert_eq!(Fraction::approximate(2.0, 10), Fraction::new(2, 1));
+		assert_eq!(Fraction::approximate(0.1, 1000000), Fraction::new(1, 10));
+		assert_eq!(Fraction::approximate(1.0 / 3.0, 100), Fraction::new(1, 3));
+		assert_eq!(Fraction::approximate(std::f64::consts::PI, 100), Fraction::new(311, 99));
+		assert_eq!(Fraction::approximate(std::f64::consts::PI, 7), Fraction::new(22, 7));
+		assert_eq!(Fraction::approximate(-0.6667, 3), Fraction::new(-2, 3));
+		assert_eq!(Fraction::approximate(1e-30, 1000), Fraction::new(0, 1));
+		assert_eq!(Fraction::approximate(1e30, 1000), None);
+		assert_eq!(Fraction::approximate(f64::NAN, 1000), None);
+		assert_eq!(Fraction::approximate(0.75, 0), Fraction::new(1, 1));
+
+		let cases = [
+			((7, 4), "7/4", "1 3/4", "1 ¾"),
+			((-7, 4), "-7/4", "-1 3/4", "-1 ¾"),
+			((3, 1), "3", "3", "3"),
+			((0, 1), "0", "0", "0"),
+			((1, 10), "1/10", "1/10", "⅒"),
+			((35, 32), "35/32", "1 3/32", "1 ³⁄₃₂"),
+			((100, 7), "100/7", "14 2/7", "14 ²⁄₇"),
+		];
+		for ((numerator, denominator), ascii, mixed, unicode) in cases {
+			let fraction = Fraction::new(numerator, denominator).unwrap();
+			assert_eq!(format_fraction(fraction, FractionStyle::Ascii, none.clone()), ascii);
+			assert_eq!(format_fraction(fraction, FractionStyle::Mixed, none.clone()), mixed);
+			assert_eq!(format_fraction(fraction, FractionStyle::Unicode, none.clone()), unicode);
+			assert_eq!(parse_fraction(ascii), Ok(fraction));
+			assert_eq!(parse_fraction(mixed), Ok(fraction));
+			assert_eq!(parse_fraction(unicode), Ok(fraction));
+		}
+
+		assert_eq!(parse_fraction(" 2½ "), Ok(Fraction { numerator: 5, denominator: 2 }));
+		assert_eq!(parse_fraction("2   1/2"), Ok(Fraction { numerator: 5, denominator: 2 }));
+		assert_eq!(parse_fraction("1⁄2"), Ok(Fraction { numerator: 1, denominator: 2 }));
+		assert_eq!(parse_fraction(""), Err(ParseFractionKind::Empty));
+		assert_eq!(parse_fraction("-"), Err(ParseFractionKind::InvalidCharacter));
+		assert_eq!(parse_fraction("1/2/3"), Err(ParseFractionKind::InvalidCharacter));
+		assert_eq!(parse_fraction("2 ½x"), Err(ParseFractionKind::InvalidCharacter));
+		assert_eq!(parse_fraction("1.5"), Err(ParseFractionKind::InvalidCharacter));
+		assert_eq!(parse_fraction("2 1/0"), Err(ParseFractionKind::ZeroDenominator));
+		assert_eq!(parse_fraction("99999999999999999999/2"), Err(ParseFractionKind::Overflow));
+		assert_eq!(parse_fraction("9223372036854775807 1/2"), Err(ParseFractionKind::Overflow));
+	}
+	#[test]
+	fn test_bijective() {
+		let columns = [
+			(1, "A"), (2, "B"), (26, "Z"), (27, "AA"), (28, "AB"), (52, "AZ"), (53, "BA"),
+			(702, "ZZ"), (703, "AAA"), (16384, "XFD"),
+		];
+		for (number, name) in columns {
+			assert_eq!(itoa_column(number, Case::Upper), Some(name.to_string()));
+			assert_eq!(itoa_column(number, Case::Lower), Some(name.to_lowercase()));
+			assert_eq!(atoi_column(name), Ok(number));
+			assert_eq!(atoi_column(&name.to_lowercase()), Ok(number));
+		}
+		assert_eq!(itoa_column(0, Case::Upper), None);
+		assert_eq!(itoa_column(usize::MAX, Case::Upper).map(|e| atoi_column(&e)), Some(Ok(usize::MAX)));
+		assert_eq!(atoi_column(""), Err(ParseIntKind::Empty));
+		assert_eq!(atoi_column("A1"), Err(ParseIntKind::InvalidCharacter));
+		assert_eq!(atoi_column("ZZZZZZZZZZZZZZZ"), Err(ParseIntKind::Overflow));
+
+		let alphabet = ['a', 'b', 'c'];
+		for number in 1..200 {
+			let text = itoa_bijective(number, &alphabet).unwrap();
+			assert_eq!(atoi_bijective(&text, &alphabet), Ok(number));
+		}
+		assert_eq!(itoa_bijective(5, &[]), None);
+		assert_eq!(itoa_bijective(4, &['x']), Some("xxxx".to_string()));
+
+		let mut cell = CellReference::new(1048576, 16384);
+		assert_eq!(format_cell(&cell, CellNotation::A1, Case::Upper), Some("XFD1048576".to_string()));
+		assert_eq!(format_cell(&cell, CellNotation::R1C1, Case::Lower), Some("r1048576c16384".to_string()));
+		cell.absolute_row = true;
+		assert_eq!(format_cell(&cell, CellNotation::A1, Case::Upper), Some("XFD$1048576".to_string()));
+		assert_eq!(format_cell(&CellReference::new(0, 1), CellNotation::A1, Case::Upper), None);
+		assert_eq!(format_cell(&CellReference::new(1, 0), CellNotation::R1C1, Case::Upper), None);
+
+		assert_eq!(parse_cell("XFD$1048576", CellNotation::A1), Ok(cell));
+		assert_eq!(parse_cell("$B3", CellNotation::A1), Ok(CellReference {
+			row: 3,
+			column: 2,
+			absolute_row: false,
+			absolute_column: true,
+		}));
+		assert_eq!(parse_cell("r1c1", CellNotation::R1C1), Ok(CellReference {
+			row: 1,
+			column: 1,
+			absolute_row: true,
+			absolute_column: true,
+		}));
+		assert_eq!(parse_cell("", CellNotation::A1), Err(ParseCellKind::Empty));
+		assert_eq!(parse_cell("12", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("B", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("B01", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("$$B1", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("B1 ", CellNotation::A1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("B99999999999999999999", CellNotation::A1), Err(ParseCellKind::Overflow));
+		assert_eq!(parse_cell("R1", CellNotation::R1C1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("R[-1]C2", CellNotation::R1C1), Err(ParseCellKind::InvalidCharacter));
+		assert_eq!(parse_cell("R0C2", CellNotation::R1C1), Err(ParseCellKind::InvalidCharacter));
+	}
+	#[test]
+	fn test_ranges() {
+		let default = RangeOptions::default();
+		let strict = RangeOptions::new(Base::new(10), false, false, false, false);
+		let hex = RangeOptions::new(Base::new(16), true, true, true, true);
+		assert_eq!(parse_ranges("7", &default), Ok(vec![NumberRange::new(7, Some(7), 1)]));
+		assert_eq!(parse_ranges(" 1 - 3 , 2-6,8 ", &default), Ok(vec![NumberRange::new(1, Some(6), 1), NumberRange::new(8, Some(8), 1)]));
+		assert_eq!(parse_ranges("1-3,4-6", &default), Ok(vec![NumberRange::new(1, Some(6), 1)]));
+		assert_eq!(parse_ranges("1-3,4-6", &strict), Ok(vec![NumberRange::new(1, Some(3), 1), NumberRange::new(4, Some(6), 1)]));
+		assert_eq!(parse_ranges("5,5,1", &strict), Ok(vec![NumberRange::new(1, Some(1), 1), NumberRange::new(5, Some(5), 1)]));
+		assert_eq!(parse_ranges("1-10:3", &default), Ok(vec![NumberRange::new(1, Some(10), 3)]));
+		assert_eq!(parse_ranges("1-11:3", &default), Ok(vec![NumberRange::new(1, Some(10), 3)]));
+		assert_eq!(parse_ranges("1-2:3", &default), Ok(vec![NumberRange::new(1, Some(1), 1)]));
+		assert_eq!(parse_ranges("10-:5", &default), Ok(vec![NumberRange::new(10, None, 5)]));
+		assert_eq!(parse_ranges("5-,1-3,20-30", &default), Ok(vec![NumberRange::new(1, Some(3), 1), NumberRange::new(5, None, 1)]));
+		assert_eq!(parse_ranges("0b101,0o7,0X1f", &default), Ok(vec![
+			NumberRange::new(5, Some(5), 1),
+			NumberRange::new(7, Some(7), 1),
+			NumberRange::new(31, Some(31), 1),
+		]));
+		assert_eq!(parse_ranges("0b1-ff", &hex), Ok(vec![NumberRange::new(0xb1, Some(0xff), 1)]));
+		assert_eq!(parse_ranges("0x10-0x12", &hex), Ok(vec![NumberRange::new(16, Some(18), 1)]));
+
+		assert_eq!(parse_ranges("", &default), Err(ParseRangeKind::Empty));
+		assert_eq!(parse_ranges("1,,2", &default), Err(ParseRangeKind::Empty));
+		assert_eq!(parse_ranges("1-x", &default), Err(ParseRangeKind::InvalidNumber("x".to_string())));
+		assert_eq!(parse_ranges("-5", &default), Err(ParseRangeKind::InvalidNumber("".to_string())));
+		assert_eq!(parse_ranges("0x", &default), Err(ParseRangeKind::InvalidNumber("0x".to_string())));
+		assert_eq!(parse_ranges("0x10", &strict), Err(ParseRangeKind::InvalidNumber("0x10".to_string())));
+		assert_eq!(parse_ranges("5-1", &default), Err(ParseRangeKind::Reversed("5-1".to_string())));
+		assert_eq!(parse_ranges("1-5:0", &default), Err(ParseRangeKind::InvalidStep("1-5:0".to_string())));
+		assert_eq!(parse_ranges("1-5:2", &strict), Err(ParseRangeKind::InvalidStep("1-5:2".to_string())));
+		assert_eq!(parse_ranges("5-", &strict), Err(ParseRangeKind::OpenRange("5-".to_string())));
+		assert_eq!(parse_ranges("99999999999999999999", &default), Err(ParseRangeKind::Overflow));
+
+		assert_eq!(format_ranges(&[], &default), "");
+		assert_eq!(format_ranges(&[3], &default), "3");
+		assert_eq!(format_ranges(&[1, 2], &default), "1,2");
+		assert_eq!(format_ranges(&[5, 3, 4, 3, 1], &default), "1,3-5");
+		assert_eq!(format_ranges(&[1, 3, 5], &default), "1,3,5");
+		assert_eq!(format_ranges(&[1, 3, 5, 7], &default), "1-7:2");
+		assert_eq!(format_ranges(&[1, 3, 5, 7], &strict), "1,3,5,7");
+		assert_eq!(format_ranges(&[2, 4, 6, 8, 9, 10, 11], &default), "2-8:2,9-11");
+		assert_eq!(format_ranges(&[255, 256, 257, 300], &hex), "0xff-0x101,0x12c");
+
+		let numbers = [0, 1, 2, 3, 10, 20, 30, 40, 41, 43, 44, 45, 99];
+		let text = format_ranges(&numbers, &default);
+		let ranges = parse_ranges(&text, &RangeOptions::new(Base::new(10), true, true, true, false)).unwrap();
+		assert_eq!((0..100).filter(|e| ranges.iter().any(|range| range.contains(*e))).collect::<Vec<usize>>(), numbers);
+	}
+	#[test]
+	fn test_literal() {
+		let value = |o: &str, dialect: LiteralDialect| parse_literal(o, dialect).map(|e| e.value);
+		let rust = LiteralDialect::Rust;
+		let c = LiteralDialect::C;
+		let python = LiteralDialect::Python;
+
+		assert_eq!(value("0", rust), Ok(0));
+		assert_eq!(value("007", rust), Ok(7));
+		assert_eq!(value("0b1010_1010", rust), Ok(0xaa));
+		assert_eq!(value("0o_17", rust), Ok(15));
+		assert_eq!(value("1__000__", rust), Ok(1000));
+		assert_eq!(value("0x1f32", rust), Ok(0x1f32));
+		assert_eq!(value("340282366920938463463374607431768211455", rust), Ok(u128::MAX));
+		assert_eq!(value("127i8", rust), Ok(127));
+		assert_eq!(value("b'A'", rust), Ok(65));
+		assert_eq!(value(r"b'\x7f'", rust), Ok(127));
+		assert_eq!(value(r"b'\xFF'", rust), Ok(255));
+		assert_eq!(value(r"b'\''", rust), Ok(39));
+		assert_eq!(value(r"b'\0'", rust), Ok(0));
+		assert_eq!(parse_literal("0b11u32", rust), Ok(IntegerLiteral {
+			value: 3,
+			base: Some(Base::new(2)),
+			suffix: Some("u32".to_string()),
+		}));
+		assert_eq!(parse_literal("", rust), Err(ParseLiteralKind::Empty));
+		assert_eq!(parse_literal("_1", rust), Err(ParseLiteralKind::InvalidCharacter(0)));
+		assert_eq!(parse_literal("0x_", rust), Err(ParseLiteralKind::InvalidCharacter(3)));
+		assert_eq!(parse_literal("0b102", rust), Err(ParseLiteralKind::InvalidCharacter(4)));
+		assert_eq!(parse_literal("0XFF", rust), Err(ParseLiteralKind::InvalidSuffix(1)));
+		assert_eq!(parse_literal("1u7", rust), Err(ParseLiteralKind::InvalidSuffix(1)));
+		assert_eq!(parse_literal("128i8", rust), Err(ParseLiteralKind::Overflow));
+		assert_eq!(parse_literal("340282366920938463463374607431768211456", rust), Err(ParseLiteralKind::Overflow));
+		assert_eq!(parse_literal("b'AB'", rust), Err(ParseLiteralKind::InvalidCharacter(3)));
+		assert_eq!(parse_literal("b'\t'", rust), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("b'é'", rust), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal(r"b'\a'", rust), Err(ParseLiteralKind::InvalidCharacter(3)));
+		assert_eq!(parse_literal("'A'", rust), Err(ParseLiteralKind::InvalidCharacter(0)));
+
+		assert_eq!(parse_literal("0", c).map(|e| e.base), Ok(Some(Base::new(8))));
+		assert_eq!(value("0x7FFFFFFF", c), Ok(0x7fffffff));
+		assert_eq!(value("0B101", c), Ok(5));
+		assert_eq!(value("1'000'000", c), Ok(1000000));
+		assert_eq!(value("18446744073709551615ULL", c), Ok(u64::MAX as u128));
+		for suffix in ["u", "U", "l", "L", "ul", "lu", "LU", "ll", "LL", "ull", "LLu", "Ull"] {
+			assert_eq!(parse_literal(&format!("42{}", suffix), c).map(|e| e.suffix), Ok(Some(suffix.to_string())));
+		}
+		assert_eq!(value(r"'\n'", c), Ok(10));
+		assert_eq!(value(r"'\101'", c), Ok(65));
+		assert_eq!(value(r"'\0'", c), Ok(0));
+		assert_eq!(value(r"'\x41'", c), Ok(65));
+		assert_eq!(value(r"'\?'", c), Ok(63));
+		assert_eq!(value("'\"'", c), Ok(34));
+		assert_eq!(parse_literal("42lL", c), Err(ParseLiteralKind::InvalidSuffix(2)));
+		assert_eq!(parse_literal("42uu", c), Err(ParseLiteralKind::InvalidSuffix(2)));
+		assert_eq!(parse_literal("42i64", c), Err(ParseLiteralKind::InvalidSuffix(2)));
+		assert_eq!(parse_literal("09", c), Err(ParseLiteralKind::InvalidCharacter(1)));
+		assert_eq!(parse_literal("1''0", c), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("10'", c), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("0x'1", c), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("18446744073709551616", c), Err(ParseLiteralKind::Overflow));
+		assert_eq!(parse_literal(r"'\x100'", c), Err(ParseLiteralKind::Overflow));
+		assert_eq!(parse_literal(r"'\x'", c), Err(ParseLiteralKind::InvalidCharacter(3)));
+		assert_eq!(parse_literal(r"'\q'", c), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("''", c), Err(ParseLiteralKind::InvalidCharacter(1)));
+		assert_eq!(parse_literal("'A", c), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("'A'x", c), Err(ParseLiteralKind::InvalidCharacter(3)));
+
+		assert_eq!(value("0", python), Ok(0));
+		assert_eq!(value("000", python), Ok(0));
+		assert_eq!(value("0_0", python), Ok(0));
+		assert_eq!(value("1_000", python), Ok(1000));
+		assert_eq!(value("0X_ff", python), Ok(255));
+		assert_eq!(value("0B1_0", python), Ok(2));
+		assert_eq!(parse_literal("0o17", python).map(|e| e.base), Ok(Some(Base::new(8))));
+		assert_eq!(parse_literal("017", python), Err(ParseLiteralKind::InvalidCharacter(0)));
+		assert_eq!(parse_literal("1_", python), Err(ParseLiteralKind::InvalidCharacter(1)));
+		assert_eq!(parse_literal("_1", python), Err(ParseLiteralKind::InvalidCharacter(0)));
+		assert_eq!(parse_literal("0x__1", python), Err(ParseLiteralKind::InvalidCharacter(3)));
+		assert_eq!(parse_literal("10L", python), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("0o8", python), Err(ParseLiteralKind::InvalidCharacter(2)));
+		assert_eq!(parse_literal("'A'", python), Err(ParseLiteralKind::InvalidCharacter(0)));
+	}
+	#[test]
+	fn test_c_format_precision() {
+		assert_eq!(c_format("%.5d|%.5i|%.0d|%.0d|%.d".to_string(),
+			vec![
+				CFormatArgument::Int32(42),
+				CFormatArgument::Int32(-42),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(7),
+				CFormatArgument::Int32(0),
+			]
+		), "00042|-00042||7|");
+		assert_eq!(c_format("%.4x|%.4X|%.4o|%.3u|%.3hhu|%.6llx".to_string(),
+			vec![
+				CFormatArgument::UInt32(255),
+				CFormatArgument::UInt32(255),
+				CFormatArgument::UInt32(8),
+				CFormatArgument::UInt32(1234),
+				CFormatArgument::UInt8(5),
+				CFormatArgument::UInt64(0xabc),
+			]
+		), "00ff|00FF|0010|1234|005|000abc");
+		assert_eq!(c_format("[%8.5d]|[%-8.5d]|[%08.5d]|[%08d]".to_string(),
+			vec![
+				CFormatArgument::Int32(42),
+				CFormatArgument::Int32(42),
+				CFormatArgument::Int32(42),
+				CFormatArgument::Int32(42),
+			]
+		), "[   00042]|[00042   ]|[   00042]|[00000042]");
+		assert_eq!(c_format("[%.3s]|[%.0s]|[%.10s]|[%5.2s]|[%-5.2s]|[%.2s]".to_string(),
+			vec![
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::String("äöü".to_string()),
+			]
+		), "[abc]|[]|[abcdef]|[   ab]|[ab   ]|[äö]");
+		assert_eq!(c_format("[%.*d]|[%*.*s]|[%.*d]".to_string(),
+			vec![
+				CFormatArgument::IntSize(4),
+				CFormatArgument::Int32(7),
+				CFormatArgument::IntSize(-6),
+				CFormatArgument::IntSize(3),
+				CFormatArgument::String("abcdef".to_string()),
+				CFormatArgument::IntSize(-1),
+				CFormatArgument::Int32(7),
+			]
+		), "[0007]|[abc   ]|[7]");
+		assert_eq!(c_format("%~.3d|%3c|%-3c|".to_string(),
+			vec![
+				CFormatArgument::Int32(2),
+				CFormatArgument::Character('a'),
+				CFormatArgument::Character('b'),
+			]
+		), "002nd|  a|b  |");
+		/* precision argument of wrong type stops formatting */
+		assert_eq!(c_format("a%.*db".to_string(),
+			vec![
+				CFormatArgument::Int32(4),
+				CFormatArgument::Int32(7),
+			]
+		), "a");
+	}
+	#[test]
+	fn test_c_format_flags() {
+		/* expected output is produced by glibc printf */
+		let signed = [
+			("%-08d", 42, "42      "),
+			("%-08d", -42, "-42     "),
+			("%-08d", 0, "0       "),
+			("%0-8d", 42, "42      "),
+			("%0-8d", -42, "-42     "),
+			("%0-8d", 0, "0       "),
+			("%+d", 42, "+42"),
+			("%+d", -42, "-42"),
+			("%+d", 0, "+0"),
+			("% d", 42, " 42"),
+			("% d", -42, "-42"),
+			("% d", 0, " 0"),
+			("%+ d", 42, "+42"),
+			("%+ d", -42, "-42"),
+			("%+ d", 0, "+0"),
+			("% +d", 42, "+42"),
+			("% +d", -42, "-42"),
+			("% +d", 0, "+0"),
+			("%+05d", 42, "+0042"),
+			("%+05d", -42, "-0042"),
+			("%+05d", 0, "+0000"),
+			("% 05d", 42, " 0042"),
+			("% 05d", -42, "-0042"),
+			("% 05d", 0, " 0000"),
+			("%05d", 42, "00042"),
+			("%05d", -42, "-0042"),
+			("%05d", 0, "00000"),
+			("%-+6d", 42, "+42   "),
+			("%-+6d", -42, "-42   "),
+			("%-+6d", 0, "+0    "),
+			("%+.3d", 42, "+042"),
+			("%+.3d", -42, "-042"),
+			("%+.3d", 0, "+000"),
+			("%+08.3d", 42, "    +042"),
+			("%+08.3d", -42, "    -042"),
+			("%+08.3d", 0, "    +000"),
+			("%++--d", 42, "+42"),
+			("%++--d", -42, "-42"),
+			("%++--d", 0, "+0"),
+			("%+i", 42, "+42"),
+			("%+i", -42, "-42"),
+			("%+i", 0, "+0"),
+			("%00005d", 42, "00042"),
+			("%00005d", -42, "-0042"),
+			("%00005d", 0, "00000"),
+		];
+		for (format, value, expected) in signed {
+			assert_eq!(c_format(format.to_string(), vec![CFormatArgument::Int32(value)]), expected, "{}", format);
+		}
+		let unsigned = [
+			("%#x", 31, "0x1f"),
+			("%#x", 0, "0"),
+			("%#x", 8, "0x8"),
+			("%#X", 31, "0X1F"),
+			("%#X", 0, "0"),
+			("%#X", 8, "0X8"),
+			("%#o", 31, "037"),
+			("%#o", 0, "0"),
+			("%#o", 8, "010"),
+			("%#08x", 31, "0x00001f"),
+			("%#08x", 0, "00000000"),
+			("%#08x", 8, "0x000008"),
+			("%#-8x", 31, "0x1f    "),
+			("%#-8x", 0, "0       "),
+			("%#-8x", 8, "0x8     "),
+			("%#.4x", 31, "0x001f"),
+			("%#.4x", 0, "0000"),
+			("%#.4x", 8, "0x0008"),
+			("%#.0x", 31, "0x1f"),
+			("%#.0x", 0, ""),
+			("%#.0x", 8, "0x8"),
+			("%#.0o", 31, "037"),
+			("%#.0o", 0, "0"),
+			("%#.0o", 8, "010"),
+			("%#5o", 31, "  037"),
+			("%#5o", 0, "    0"),
+			("%#5o", 8, "  010"),
+			("%#08o", 31, "00000037"),
+			("%#08o", 0, "00000000"),
+			("%#08o", 8, "00000010"),
+			("%+u", 31, "31"),
+			("%+u", 0, "0"),
+			("%+u", 8, "8"),
+			("% u", 31, "31"),
+			("% u", 0, "0"),
+			("% u", 8, "8"),
+			("%+x", 31, "1f"),
+			("%+x", 0, "0"),
+			("%+x", 8, "8"),
+			("%#010X", 31, "0X0000001F"),
+			("%#010X", 0, "0000000000"),
+			("%#010X", 8, "0X00000008"),
+			("%#.3o", 31, "037"),
+			("%#.3o", 0, "000"),
+			("%#.3o", 8, "010"),
+		];
+		for (format, value, expected) in unsigned {
+			assert_eq!(c_format(format.to_string(), vec![CFormatArgument::UInt32(value)]), expected, "{}", format);
+		}
+		assert_eq!(c_format("%'d|%'u|%'.7d|%'08d|%'x|%'~d".to_string(),
+			vec![
+				CFormatArgument::Int32(-1234567),
+				CFormatArgument::UInt32(1000),
+				CFormatArgument::Int32(1234),
+				CFormatArgument::Int32(1234),
+				CFormatArgument::UInt32(0x123456),
+				CFormatArgument::Int32(1001),
+			]
+		), "-1,234,567|1,000|0,001,234|0001,234|123456|1,001st");
+		assert_eq!(c_format("%#lx|%+lld|% hhd|%#hho|%~+d".to_string(),
+			vec![
+				CFormatArgument::UIntSize(255),
+				CFormatArgument::Int64(7),
+				CFormatArgument::Int8(-7),
+				CFormatArgument::UInt8(8),
+				CFormatArgument::Int32(3),
+			]
+		), "0xff|+7|-7|010|+3rd");
+		assert_eq!(c_format("[%05s]|[%-05c]".to_string(),
+			vec![
+				CFormatArgument::String("ab".to_string()),
+				CFormatArgument::Character('c'),
+			]
+		), "[   ab]|[c    ]");
+	}
+	#[test]
+	fn test_c_format_float() {
+		/* expected output is produced by glibc printf */
+		let cases = [
+			("%f", -0.0, "-0.000000"),
+			("%f", 2.5, "2.500000"),
+			("%f", -314159.0 / 100000.0, "-3.141590"),
+			("%.0f", 1e-5, "0"),
+			("%.0f", 5e-324, "0"),
+			("%.0f", 999999.5, "1000000"),
+			("%#.0f", f64::INFINITY, "inf"),
+			("%#.0f", -f64::NAN, "-nan"),
+			("%#.0f", 1.5, "2."),
+			("%.2f", 0.1, "0.10"),
+			("%.2f", 123456789.0, "123456789.00"),
+			("%.2f", 1.0 / 3.0, "0.33"),
+			("%10.3f", 9.9999999, "    10.000"),
+			("%10.3f", 1e-310, "     0.000"),
+			("%10.3f", 1e100, "10000000000000000159028911097599180468360808563945281389781327557747838772170381060813469985856815104.000"),
+			("%-10.3f", 0.125, "0.125     "),
+			("%-10.3f", -0.0, "-0.000    "),
+			("%-10.3f", 2.5, "2.500     "),
+			("%010.3f", -314159.0 / 100000.0, "-00003.142"),
+			("%010.3f", 1e-5, "000000.000"),
+			("%010.3f", 5e-324, "000000.000"),
+			("%+f", 999999.5, "+999999.500000"),
+			("%+f", f64::INFINITY, "+inf"),
+			("%+f", -f64::NAN, "-nan"),
+			("% f", 1.5, " 1.500000"),
+			("% f", 0.1, " 0.100000"),
+			("% f", 123456789.0, " 123456789.000000"),
+			("%F", 1.0 / 3.0, "0.333333"),
+			("%F", 9.9999999, "10.000000"),
+			("%F", 1e-310, "0.000000"),
+			("%e", 1e100, "1.000000e+100"),
+			("%e", 0.125, "1.250000e-01"),
+			("%e", -0.0, "-0.000000e+00"),
+			("%.0e", 2.5, "2e+00"),
+			("%.0e", -314159.0 / 100000.0, "-3e+00"),
+			("%.0e", 1e-5, "1e-05"),
+			("%#.0e", 5e-324, "5.e-324"),
+			("%#.0e", 999999.5, "1.e+06"),
+			("%#.0e", f64::INFINITY, "inf"),
+			("%E", -f64::NAN, "-NAN"),
+			("%E", 1.5, "1.500000E+00"),
+			("%E", 0.1, "1.000000E-01"),
+			("%+012.3e", 123456789.0, "+001.235e+08"),
+			("%+012.3e", 1.0 / 3.0, "+003.333e-01"),
+			("%+012.3e", 9.9999999, "+001.000e+01"),
+			("%g", 1e-310, "1e-310"),
+			("%g", 1e100, "1e+100"),
+			("%g", 0.125, "0.125"),
+			("%.0g", -0.0, "-0"),
+			("%.0g", 2.5, "2"),
+			("%.0g", -314159.0 / 100000.0, "-3"),
+			("%.1g", 1e-5, "1e-05"),
+			("%.1g", 5e-324, "5e-324"),
+			("%.1g", 999999.5, "1e+06"),
+			("%#g", f64::INFINITY, "inf"),
+			("%#g", -f64::NAN, "-nan"),
+			("%#g", 1.5, "1.50000"),
+			("%G", 0.1, "0.1"),
+			("%G", 123456789.0, "1.23457E+08"),
+			("%G", 1.0 / 3.0, "0.333333"),
+			("%.3g", 9.9999999, "10"),
+			("%.3g", 1e-310, "1e-310"),
+			("%.3g", 1e100, "1e+100"),
+			("%#.3g", 0.125, "0.125"),
+			("%#.3g", -0.0, "-0.00"),
+			("%#.3g", 2.5, "2.50"),
+			("%10.4g", -314159.0 / 100000.0, "    -3.142"),
+			("%10.4g", 1e-5, "     1e-05"),
+			("%10.4g", 5e-324, "4.941e-324"),
+			("%-+10g", 999999.5, "+1e+06    "),
+			("%-+10g", f64::INFINITY, "+inf      "),
+			("%-+10g", -f64::NAN, "-nan      "),
+			("%a", 1.5, "0x1.8p+0"),
+			("%a", 0.1, "0x1.999999999999ap-4"),
+			("%a", 123456789.0, "0x1.d6f3454p+26"),
+			("%A", 1.0 / 3.0, "0X1.5555555555555P-2"),
+			("%A", 9.9999999, "0X1.3FFFFFCA501ADP+3"),
+			("%A", 1e-310, "0X0.012688B70E62BP-1022"),
+			("%.0a", 1e100, "0x1p+332"),
+			("%.0a", 0.125, "0x1p-3"),
+			("%.0a", -0.0, "-0x0p+0"),
+			("%.1a", 2.5, "0x1.4p+1"),
+			("%.1a", -314159.0 / 100000.0, "-0x1.9p+1"),
+			("%.1a", 1e-5, "0x1.5p-17"),
+			("%.2a", 5e-324, "0x0.00p-1022"),
+			("%.2a", 999999.5, "0x1.e8p+19"),
+			("%.2a", f64::INFINITY, "inf"),
+			("%#.0a", -f64::NAN, "-nan"),
+			("%#.0a", 1.5, "0x2.p+0"),
+			("%#.0a", 0.1, "0x2.p-4"),
+			("%010a", 123456789.0, "0x1.d6f3454p+26"),
+			("%010a", 1.0 / 3.0, "0x1.5555555555555p-2"),
+			("%010a", 9.9999999, "0x1.3fffffca501adp+3"),
+			("%.15a", 1e-310, "0x0.012688b70e62b00p-1022"),
+			("%.15a", 1e100, "0x1.249ad2594c37d00p+332"),
+			("%.15a", 0.125, "0x1.000000000000000p-3"),
+			("%+a", -0.0, "-0x0p+0"),
+			("%+a", 2.5, "+0x1.4p+1"),
+			("%+a", -314159.0 / 100000.0, "-0x1.921f9f01b866ep+1"),
+			("%020.3f", 1e-5, "0000000000000000.000"),
+			("%020.3f", 5e-324, "0000000000000000.000"),
+			("%020.3f", 999999.5, "0000000000999999.500"),
+			("%.20f", f64::INFINITY, "inf"),
+			("%.20f", -f64::NAN, "-nan"),
+			("%.20f", 1.5, "1.50000000000000000000"),
+			("%.17g", 0.1, "0.10000000000000001"),
+			("%.17g", 123456789.0, "123456789"),
+			("%.17g", 1.0 / 3.0, "0.33333333333333331"),
+		];
+		for (format, value, expected) in cases {
+			assert_eq!(c_format(format.to_string(), vec![CFormatArgument::Float64(value)]), expected, "{} {}", format, value);
+		}
+		assert_eq!(c_format("%#g|%#.3g|%.0a|%#.0a|%g".to_string(),
+			vec![
+				CFormatArgument::Float64(999999.5),
+				CFormatArgument::Float64(999.9996),
+				CFormatArgument::Float64(1.5),
+				CFormatArgument::Float64(2.5),
+				CFormatArgument::Float32(0.1),
+			]
+		), "1.e+06|1.e+03|0x2p+0|0x1.p+1|0.1");
+		assert_eq!(c_format("%'.2f|%'g|%'e|%lf|%5.1F".to_string(),
+			vec![
+				CFormatArgument::Float64(-1234567.891),
+				CFormatArgument::Float64(123456.0),
+				CFormatArgument::Float64(123456.0),
+				CFormatArgument::Float64(0.5),
+				CFormatArgument::Float64(f64::NAN),
+			]
+		), "-1,234,567.89|123,456|1.234560e+05|0.500000|  NAN");
+		/* floating point conversions don't accept integers */
+		assert_eq!(c_format("a%fb".to_string(), vec![CFormatArgument::Int32(1)]), "a");
+		assert_eq!(c_format("a%hfb".to_string(), vec![CFormatArgument::Float64(1.0)]), "a");
+	}
+	#[test]
+	fn test_try_c_format() {
+		assert_eq!(try_c_format("%s=%5.1f%%|%*d".to_string(),
+			vec![
+				CFormatArgument::String("load".to_string()),
+				CFormatArgument::Float32(99.5),
+				CFormatArgument::IntSize(-3),
+				CFormatArgument::Int32(7),
+			]
+		), Ok("load= 99.5%|7  ".to_string()));
+		assert_eq!(try_c_format("a %d b %d".to_string(), vec![CFormatArgument::Int32(1)]), Err(FormatError::MissingArgument {
+			offset: 7,
+			spec: "%d".to_string(),
+			index: 1,
+		}));
+		assert_eq!(try_c_format("%d %d".to_string(),
+			vec![
+				CFormatArgument::Int32(1),
+				CFormatArgument::Int32(2),
+				CFormatArgument::Int32(3),
+			]
+		), Err(FormatError::ExtraArguments {
+			used: 2,
+			supplied: 3,
+		}));
+		assert_eq!(try_c_format("é%-*s".to_string(),
+			vec![
+				CFormatArgument::Int32(5),
+				CFormatArgument::String("x".to_string()),
+			]
+		), Err(FormatError::Mismatch {
+			offset: 2,
+			spec: "%-*s".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::IntSize,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		assert_eq!(try_c_format("%lld".to_string(), vec![CFormatArgument::Int32(1)]), Err(FormatError::Mismatch {
+			offset: 0,
+			spec: "%lld".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::Int64,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		assert_eq!(try_c_format("%f".to_string(), vec![CFormatArgument::String("1".to_string())]), Err(FormatError::Mismatch {
+			offset: 0,
+			spec: "%f".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::Float64,
+			supplied: CFormatArgumentKind::String,
+		}));
+		assert_eq!(try_c_format("x%hsy".to_string(), vec![CFormatArgument::String("1".to_string())]), Err(FormatError::InvalidSpecifier {
+			offset: 1,
+			spec: "%hs".to_string(),
+		}));
+		assert_eq!(try_c_format("%y".to_string(), vec![]), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%y".to_string(),
+		}));
+		assert_eq!(try_c_format("%-5.".to_string(), vec![]), Err(FormatError::Incomplete {
+			offset: 0,
+			spec: "%-5.".to_string(),
+		}));
+		/* c_format returns text before failed specification instead of panicking, and ignores extra arguments */
+		assert_eq!(c_format("a %d b %d".to_string(), vec![CFormatArgument::Int32(1)]), "a 1 b ");
+		assert_eq!(c_format("%d|%y|".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), "1||");
+	}
+	#[test]
+	fn test_c_format_positional() {
+		assert_eq!(c_format("%3$s %1$s %2$s %1$s".to_string(),
+			vec![
+				CFormatArgument::String("a".to_string()),
+				CFormatArgument::String("b".to_string()),
+				CFormatArgument::String("c".to_string()),
+			]
+		), "c a b a");
+		assert_eq!(c_format("[%1$*2$.*3$f]|[%1$-*2$.1f]|%%|[%10$d]".to_string(),
+			vec![
+				CFormatArgument::Float64(1.23456),
+				CFormatArgument::IntSize(8),
+				CFormatArgument::IntSize(3),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(0),
+				CFormatArgument::Int32(10),
+			]
+		), "[   1.235]|[1.2     ]|%|[10]");
+		/* `1d` without `$` is still width */
+		assert_eq!(c_format("%1d|%12d".to_string(), vec![CFormatArgument::Int32(5), CFormatArgument::Int32(6)]), "5|           6");
+		/* arguments skipped between positions are reported */
+		assert_eq!(try_c_format("%2$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), Err(FormatError::SkippedArgument {
+			index: 0,
+		}));
+		assert_eq!(c_format("%2$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), "2");
+		assert_eq!(try_c_format("%1$d %3$d %1$d".to_string(),
+			vec![
+				CFormatArgument::Int32(1),
+				CFormatArgument::Int32(2),
+				CFormatArgument::Int32(3),
+			]
+		), Err(FormatError::SkippedArgument {
+			index: 1,
+		}));
+		assert_eq!(try_c_format("%2$d %1$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), Ok("2 1".to_string()));
+		assert_eq!(try_c_format("%2$*1$d".to_string(), vec![CFormatArgument::IntSize(3), CFormatArgument::Int32(2)]), Ok("  2".to_string()));
+		assert_eq!(try_c_format("%1$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), Err(FormatError::ExtraArguments {
+			used: 1,
+			supplied: 2,
+		}));
+		assert_eq!(try_c_format("%1$d %3$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), Err(FormatError::MissingArgument {
+			offset: 5,
+			spec: "%3$d".to_string(),
+			index: 2,
+		}));
+		assert_eq!(try_c_format("%1$d %s".to_string(),
+			vec![
+				CFormatArgument::Int32(1),
+				CFormatArgument::String("a".to_string()),
+			]
+		), Err(FormatError::MixedArguments {
+			offset: 5,
+			spec: "%s".to_string(),
+		}));
+		assert_eq!(try_c_format("%d %% %2$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), Err(FormatError::MixedArguments {
+			offset: 6,
+			spec: "%2$d".to_string(),
+		}));
+		assert_eq!(try_c_format("%1$*d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::IntSize(2)]), Err(FormatError::MixedArguments {
+			offset: 0,
+			spec: "%1$*d".to_string(),
+		}));
+		assert_eq!(try_c_format("%*2$d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::IntSize(2)]), Err(FormatError::MixedArguments {
+			offset: 0,
+			spec: "%*2$d".to_string(),
+		}));
+		assert_eq!(try_c_format("%1$s %1$d".to_string(), vec![CFormatArgument::String("a".to_string())]), Err(FormatError::Mismatch {
+			offset: 5,
+			spec: "%1$d".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::Int32,
+			supplied: CFormatArgumentKind::String,
+		}));
+		assert_eq!(c_format("a%1$d b%d".to_string(), vec![CFormatArgument::Int32(1), CFormatArgument::Int32(2)]), "a1 b");
+	}
+	#[test]
+	fn test_c_format_compiled() {
+		let format = CFormat::new("%2$s=%1$-*3$d|%2$.1s").unwrap();
+		for (value, name, width) in [(1, "a", 3), (-25, "bc", 5)] {
+			let arguments = [
+				CFormatArgument::Int32(value),
+				CFormatArgument::String(name.to_string()),
+				CFormatArgument::IntSize(width),
+			];
+			assert_eq!(format.format(&arguments), c_format("%2$s=%1$-*3$d|%2$.1s".to_string(), arguments.to_vec()));
+		}
+		assert_eq!(format.format(&[CFormatArgument::Int32(1), CFormatArgument::String("x".to_string()), CFormatArgument::IntSize(2)]), "x=1 |x");
+		assert_eq!(CFormat::new("").unwrap().format(&[]), "");
+		assert_eq!(CFormat::new("100%%").unwrap().try_format(&[]), Ok("100%".to_string()));
+		/* invalid format strings are rejected when compiled */
+		assert_eq!(CFormat::new("ok %d %y").unwrap_err(), FormatError::InvalidSpecifier {
+			offset: 6,
+			spec: "%y".to_string(),
+		});
+		assert_eq!(CFormat::new("%d %1$d").unwrap_err(), FormatError::MixedArguments {
+			offset: 3,
+			spec: "%1$d".to_string(),
+		});
+		assert_eq!(CFormat::new("%5").unwrap_err(), FormatError::Incomplete {
+			offset: 0,
+			spec: "%5".to_string(),
+		});
+		/* argument errors are found when rendered */
+		let format = CFormat::new("%s: %u").unwrap();
+		assert_eq!(format.format(&[CFormatArgument::String("n".to_string()), CFormatArgument::Int32(1)]), "n: ");
+		assert_eq!(format.try_format(&[CFormatArgument::String("n".to_string()), CFormatArgument::Int32(1)]), Err(FormatError::Mismatch {
+			offset: 4,
+			spec: "%u".to_string(),
+			index: 1,
+			expected: CFormatArgumentKind::UInt32,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		assert_eq!(format.try_format(&[CFormatArgument::String("n".to_string())]), Err(FormatError::MissingArgument {
+			offset: 4,
+			spec: "%u".to_string(),
+			index: 1,
+		}));
+	}
+	#[test]
+	fn test_c_format_parser() {
+		let segments: Vec<CFormatSegment> = CFormatParser::new("né %1$+08.*2$lf%%!")
+			.collect::<Result<_, FormatError>>()
+			.unwrap();
+		assert_eq!(segments, [
+			CFormatSegment::Literal {
+				text: "né ".to_string(),
+				span: 0..4,
+			},
+			CFormatSegment::Conversion {
+				spec: CFormatSpec {
+					argument: Some(1),
+					flags: CFormatFlags {
+						plus: true,
+						zero: true,
+						..CFormatFlags::default()
+					},
+					width: Some(CFormatCount::Fixed(8)),
+					precision: Some(CFormatCount::Position(2)),
+					length: CFormatLength::Long,
+					conversion: 'f',
+					specifier: CFormatTypeSpecifier::Float,
+					name: None,
+				},
+				span: 4..16,
+			},
+			CFormatSegment::Conversion {
+				spec: CFormatSpec {
+					argument: None,
+					flags: CFormatFlags::default(),
+					width: None,
+					precision: None,
+					length: CFormatLength::None,
+					conversion: '%',
+					specifier: CFormatTypeSpecifier::Escape,
+					name: None,
+				},
+				span: 16..18,
+			},
+			CFormatSegment::Literal {
+				text: "!".to_string(),
+				span: 18..19,
+			},
+		]);
+		/* re-serialized format string is equivalent to original */
+		for format in ["", "plain", "%d", "%-+ #0'~*.*lld|%.s|%hhu%y", "%3$s %1$*2$.3x", "% 0+-5i"] {
+			let serialized: String = CFormatParser::new(format)
+				.map(|e| e.unwrap().to_format())
+				.collect();
+			let reparsed: Vec<CFormatSegment> = CFormatParser::new(&serialized).map(Result::unwrap).collect();
+			let original: Vec<CFormatSegment> = CFormatParser::new(format).map(Result::unwrap).collect();
+			let without_span = |segments: Vec<CFormatSegment>| segments
+				.into_iter()
+				.map(|e| match e {
+					CFormatSegment::Literal { text, .. } => (Some(text), None),
+					CFormatSegment::Conversion { spec, .. } => (None, Some(spec)),
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(without_span(reparsed), without_span(original));
+		}
+		assert_eq!(CFormatParser::new("% 0+-5i").next().unwrap().unwrap().to_format(), "%-+ 05i");
+		assert_eq!(CFormatSegment::Literal {
+			text: "100%".to_string(),
+			span: 0..4,
+		}.to_format(), "100%%");
+		/* translation uses same argument types as original */
+		let kinds = |format: &str| {
+			let mut kinds: Vec<(usize, CFormatArgumentKind)> = CFormatParser::new(format)
+				.filter_map(|e| match e.unwrap() {
+					CFormatSegment::Conversion { spec, .. } => spec.argument.zip(spec.argument_kind()),
+					CFormatSegment::Literal { .. } => None,
+				})
+				.collect();
+			kinds.sort_by_key(|e| e.0);
+			kinds
+		};
+		assert_eq!(kinds("%1$s has %2$lu files"), kinds("%2$lu fichiers pour %1$s"));
+		assert_ne!(kinds("%1$s has %2$lu files"), kinds("%2$d fichiers pour %1$s"));
+		/* parser stops after error */
+		let mut parser = CFormatParser::new("a%hfb%d");
+		assert!(matches!(parser.next(), Some(Ok(CFormatSegment::Literal { .. }))));
+		assert_eq!(parser.next(), Some(Err(FormatError::InvalidSpecifier {
+			offset: 1,
+			spec: "%hf".to_string(),
+		})));
+		assert_eq!(parser.next(), None);
+	}
+	#[test]
+	fn test_c_format_macro() {
+		let name = "disk".to_string();
+		let used = 93.75;
+		assert_eq!(c_format!("%s: %5.1f%% (%'llu bytes)", &name, used, 512_000_000u64), "disk:  93.8% (512,000,000 bytes)");
+		assert_eq!(c_format!("%c%c|%hhd|%hu|%lx", 'o', 'k', -1i8, 65535u16, 255usize), "ok|-1|65535|ff");
+		assert_eq!(c_format!("[%*.*s]", 5isize, 2isize, name.clone()), "[   di]");
+		assert_eq!(c_format!("%3$s %1$*2$d", 7, 3isize, "x"), "x   7");
+		assert_eq!(c_format!("100%%"), "100%");
+		assert_eq!(c_format!("%g|%g,", 0.5f32, 1e10,), "0.5|1e+10,");
+		/* format string, which is not literal, isn't checked */
+		let format = "%d-%d";
+		assert_eq!(c_format!(format, 1, 2u32), "1-");
+		assert!(matches!(CFormatArgument::from("a"), CFormatArgument::String(e) if e == "a"));
+		assert_eq!(argument_count("%d %*.*f %%"), 4);
+		assert_eq!(argument_count("%3$d %1$s"), 3);
+		assert_eq!(expected_argument("%d %*.*f %%", 1), CFormatArgumentKind::IntSize as u8);
+		assert_eq!(expected_argument("%3$d %1$s", 1), UNUSED_ARGUMENT);
+	}
+	#[test]
+	fn test_c_format_coerced() {
+		/* expected output is produced by glibc printf with same values, passed as C would pass them */
+		assert_eq!(c_format_coerced("%u|%hhd|%hhu|%hd|%d|%llu|%lld|%llx|%+.3d|[%*d]".to_string(),
+			vec![
+				CFormatArgument::Int32(-1),
+				CFormatArgument::Int32(300),
+				CFormatArgument::Int32(-1),
+				CFormatArgument::UInt16(65535),
+				CFormatArgument::UInt32(4294967295),
+				CFormatArgument::Int32(-1),
+				CFormatArgument::UInt64(u64::MAX),
+				CFormatArgument::Int8(-1),
+				CFormatArgument::Int64(-5),
+				CFormatArgument::Int32(-4),
+				CFormatArgument::UInt8(7),
+			]
+		), "4294967295|44|255|-1|-1|18446744073709551615|-1|ffffffffffffffff|-005|[7   ]");
+		assert_eq!(c_format_coerced("%c%c%c|%d|%x|%.3f".to_string(),
+			vec![
+				CFormatArgument::UInt32(0x1f600),
+				CFormatArgument::Int32(-1),
+				CFormatArgument::Character('!'),
+				CFormatArgument::Character('A'),
+				CFormatArgument::Pointer(0xff as *const u8),
+				CFormatArgument::Float32(0.5),
+			]
+		), "😀\u{fffd}!|65|ff|0.500");
+		/* exact variants are unchanged */
+		assert_eq!(c_format_coerced("%d|%s".to_string(), vec![CFormatArgument::Int32(-1), CFormatArgument::String("s".to_string())]), "-1|s");
+		/* floating point and string arguments aren't coerced */
+		assert_eq!(c_format_coerced("a%fb".to_string(), vec![CFormatArgument::Int32(1)]), "a");
+		assert_eq!(c_format_coerced("a%db".to_string(), vec![CFormatArgument::Float64(1.0)]), "a");
+		let mut format = CFormat::new("%s=%lu").unwrap();
+		let arguments = [CFormatArgument::String("n".to_string()), CFormatArgument::Int32(3)];
+		assert!(!format.get_coercion());
+		assert_eq!(format.try_format(&arguments), Err(FormatError::Mismatch {
+			offset: 3,
+			spec: "%lu".to_string(),
+			index: 1,
+			expected: CFormatArgumentKind::UIntSize,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		assert_eq!(format.set_coercion(true).try_format(&arguments), Ok("n=3".to_string()));
+		assert_eq!(format.try_format(&[CFormatArgument::String("n".to_string()), CFormatArgument::String("3".to_string())]), Err(FormatError::Mismatch {
+			offset: 3,
+			spec: "%lu".to_string(),
+			index: 1,
+			expected: CFormatArgumentKind::UIntSize,
+			supplied: CFormatArgumentKind::String,
+		}));
+	}
+	#[test]
+	fn test_c_format_length() {
+		assert_eq!(c_format(
+			"%zu|%zd|%jd|%td|%.2Lf".to_string(),
+			vec![
+				CFormatArgument::UIntSize(42),
+				CFormatArgument::IntSize(-42),
+				CFormatArgument::Int64(i64::MIN),
+				CFormatArgument::IntSize(-7),
+				CFormatArgument::Float64(2.5),
+			]
+		), "42|-42|-9223372036854775808|-7|2.50");
+		assert_eq!(c_format(
+			"%w8x|%w16d|%w32d|%wf64u|%08w128X".to_string(),
+			vec![
+				CFormatArgument::UInt8(255),
+				CFormatArgument::Int16(-1),
+				CFormatArgument::Int32(42),
+				CFormatArgument::UInt64(u64::MAX),
+				CFormatArgument::UInt128(0xbeef),
+			]
+		), "ff|-1|42|18446744073709551615|0000BEEF");
+		assert_eq!(c_format("%w128d".to_string(), vec![CFormatArgument::Int128(-(1 << 100))]), "-1267650600228229401496703205376");
+		assert_eq!(c_format("%'w128u".to_string(), vec![CFormatArgument::UInt128(u128::MAX)]), "340,282,366,920,938,463,463,374,607,431,768,211,455");
+		/* 128-bit values are cast like other integers in coercion mode */
+		assert_eq!(c_format_coerced("%hhu|%w128d".to_string(), vec![CFormatArgument::UInt128(u128::MAX), CFormatArgument::Int8(-1)]), "255|-1");
+		assert_eq!(try_c_format("%w7d".to_string(), vec![CFormatArgument::Int32(1)]), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%w7d".to_string(),
+		}));
+		assert_eq!(try_c_format("%zs".to_string(), vec![CFormatArgument::String("s".to_string())]), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%zs".to_string(),
+		}));
+		assert_eq!(try_c_format("%jd".to_string(), vec![CFormatArgument::Int32(1)]), Err(FormatError::Mismatch {
+			offset: 0,
+			spec: "%jd".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::Int64,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		let segments = CFormatParser::new("%5zu%wf16x%Lg%w64d").collect::<Result<Vec<CFormatSegment>, FormatError>>().unwrap();
+		assert_eq!(segments.iter().map(CFormatSegment::to_format).collect::<String>(), "%5zu%wf16x%Lg%w64d");
+		assert_eq!(c_format!("%w128u|%zd|%Le", u128::MAX, -3isize, 1.5), "340282366920938463463374607431768211455|-3|1.500000e+00");
+	}
+	#[test]
+	fn test_c_format_binary() {
+		assert_eq!(c_format(
+			"%b|%#b|%#B|%#010b|%-8b|%.6b|%#b".to_string(),
+			vec![
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(5),
+				CFormatArgument::UInt32(0),
+			]
+		), "101|0b101|0B101|0b00000101|101     |000101|0");
+		assert_eq!(c_format(
+			"%hhb|%hb|%lb|%llb|%zb|%jb|%w8b|%wf64B".to_string(),
+			vec![
+				CFormatArgument::UInt8(u8::MAX),
+				CFormatArgument::UInt16(1 << 15),
+				CFormatArgument::UIntSize(2),
+				CFormatArgument::UInt64(1 << 63),
+				CFormatArgument::UIntSize(3),
+				CFormatArgument::UInt64(4),
+				CFormatArgument::UInt8(6),
+				CFormatArgument::UInt64(7),
+			]
+		), "11111111|1000000000000000|10|1000000000000000000000000000000000000000000000000000000000000000|11|100|110|111");
+		assert_eq!(c_format("%'b|%'#hB".to_string(), vec![CFormatArgument::UInt32(0xa5), CFormatArgument::UInt16(0x1ff)]), "1010_0101|0B1_1111_1111");
+		assert_eq!(c_format("%w128b".to_string(), vec![CFormatArgument::UInt128(1 << 127)]), format!("1{}", "0".repeat(127)));
+		/* negative values are cast to unsigned in coercion mode */
+		assert_eq!(c_format_coerced("%hhb".to_string(), vec![CFormatArgument::Int32(-2)]), "11111110");
+		assert_eq!(try_c_format("%Lb".to_string(), vec![CFormatArgument::UInt32(1)]), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%Lb".to_string(),
+		}));
+		assert_eq!(c_format!("%#06hhb", 3u8), "0b0011");
+	}
+	#[test]
+	fn test_c_format_custom() {
+		#[derive(Debug, Clone)]
+		struct Point(i32, i32);
+		let mut registry = CFormatRegistry::new();
+		registry
+			.register('P', |spec: &CFormatSpec, _, _, value: &dyn CFormatCustom| match value.as_any().downcast_ref::<Point>() {
+				Some(Point(x, y)) if spec.flags.alternate => format!("({}; {})", x, y),
+				Some(Point(x, y)) => format!("{},{}", x, y),
+				None => "?".to_string(),
+			})
+			.register_named("bytes", |_: &CFormatSpec, width: Option<usize>, precision: Option<usize>, value: &dyn CFormatCustom| {
+				let bytes = value.as_any().downcast_ref::<Vec<u8>>().cloned().unwrap_or_default();
+				format!("{}:{:?}:{:?}", bytes.len(), width, precision)
+			});
+		let point = || CFormatArgument::Custom(Box::new(Point(1, -2)));
+		assert_eq!(c_format_with("%P|%#P|%-9P|%8P|%P".to_string(), vec![point(), point(), point(), point(), CFormatArgument::Custom(Box::new(0))], &registry),
+			"1,-2|(1; -2)|1,-2     |    1,-2|?");
+		assert_eq!(c_format_with("%2$*1$.3{bytes}".to_string(), vec![CFormatArgument::IntSize(-2), CFormatArgument::Custom(Box::new(vec![1u8, 2]))], &registry),
+			"2:Some(2):Some(3)");
+		/* cloned arguments keep custom values */
+		let arguments = vec![point()];
+		assert_eq!(c_format_with("<%P>".to_string(), arguments.clone(), &registry), "<1,-2>");
+		assert_eq!(arguments[0].kind(), CFormatArgumentKind::Custom);
+		/* unregistered conversions are unknown */
+		assert_eq!(c_format_with("a%Qb%{none}c".to_string(), vec![], &registry), "abc");
+		assert_eq!(c_format("%P".to_string(), vec![point()]), "");
+		assert_eq!(try_c_format_with("%P".to_string(), vec![CFormatArgument::Int32(1)], &registry), Err(FormatError::Mismatch {
+			offset: 0,
+			spec: "%P".to_string(),
+			index: 0,
+			expected: CFormatArgumentKind::Custom,
+			supplied: CFormatArgumentKind::Int32,
+		}));
+		assert_eq!(try_c_format_with("%{bytes".to_string(), vec![], &registry), Err(FormatError::Incomplete {
+			offset: 0,
+			spec: "%{bytes".to_string(),
+		}));
+		assert_eq!(try_c_format_with("%{}".to_string(), vec![], &registry), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%{}".to_string(),
+		}));
+		assert_eq!(try_c_format_with("%lP".to_string(), vec![point()], &registry), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%lP".to_string(),
+		}));
+		assert_eq!(try_c_format("%{bytes}".to_string(), vec![]), Err(FormatError::InvalidSpecifier {
+			offset: 0,
+			spec: "%{bytes}".to_string(),
+		}));
+		/* coercion doesn't convert integers to custom values */
+		let registry = Arc::new(registry);
+		let mut format = CFormat::with_registry("%3$P %1$s %2$#P", registry.clone()).unwrap();
+		assert_eq!(format.set_coercion(true).try_format(&[CFormatArgument::String("and".to_string()), point(), point()]), Ok("1,-2 and (1; -2)".to_string()));
+		assert!(format.try_format(&[CFormatArgument::String("and".to_string()), CFormatArgument::Int32(1), point()]).is_err());
+		assert_eq!(format.segments()[0].to_format(), "%3$P");
+		let segments = CFormatParser::new("%-4{bytes}").collect::<Result<Vec<CFormatSegment>, FormatError>>().unwrap();
+		assert_eq!(segments[0].to_format(), "%-4{bytes}");
+		assert!(CFormat::with_registry("%Q", registry).is_err());
+	}
+	#[test]
+	fn test_format_text_unicode() {
+		/* width counts characters, so multi-byte text is padded like ASCII text */
+		assert_eq!(format_text("né", FormatOptions::new(FormatDirection::Left(4), Some('.'))), "né..");
+		assert_eq!(format_text("€12", FormatOptions::new(FormatDirection::Right(6), None)), "   €12");
+		assert_eq!(format_text("日本", FormatOptions::new(FormatDirection::Right(3), Some('*'))), "*日本");
+		assert_eq!(format_text("ünïcödé", FormatOptions::new(FormatDirection::Left(7), Some('_'))), "ünïcödé");
+		assert_eq!(format_text("😀", FormatOptions::new(FormatDirection::Left(2), Some('-'))), "😀-");
+	}
+	#[test]
+	fn test_currency_column() {
+		let usd = Currency::from_code("USD").unwrap();
+		let eur = Currency::from_code("EUR").unwrap();
+		let jpy = Currency::from_code("JPY").unwrap();
+		let kwd = Currency::from_code("KWD").unwrap();
+		let amounts = [
+			(Decimal::new(123456, 2), &usd),
+			(Decimal::new(-5, 0), &eur),
+			(Decimal::new(1234567, 0), &jpy),
+			(Decimal::new(25, 3), &kwd),
+			(Decimal::new(-99999999, 2), &usd),
+		];
+		let accounting = CurrencyOptions::new(Locale::English, CurrencyDisplay::Symbol, true, RoundingMode::HalfEven);
+		for padding in [FormatDirection::None, FormatDirection::Left(22), FormatDirection::Right(22), FormatDirection::Center(23)] {
+			let column = format_currency_column(&amounts, accounting.clone(), padding).unwrap();
+			/* decimal points, and end of integer part of amounts without minor units, are in one column */
+			let points = column
+				.iter()
+				.map(|e| e.chars().position(|e| e == '.').unwrap_or_else(|| e.chars().position(|e| e == '¥').unwrap() + "¥1,234,567".chars().count()))
+				.collect::<Vec<usize>>();
+			assert!(points.iter().all(|e| *e == points[0]), "{:?}", column);
+			assert!(column.iter().all(|e| e.chars().count() == column[0].chars().count()), "{:?}", column);
+		}
+		assert_eq!(format_currency_column(&amounts, accounting.clone(), FormatDirection::Right(22)).unwrap(), [
+			"          $1,234.56   ",
+			"              (5.00 €)",
+			"      ¥1,234,567      ",
+			"            KD 0.025  ",
+			"       ($999,999.99)  ",
+		]);
+		assert_eq!(format_currency_column(&amounts[..2], CurrencyOptions::default(), FormatDirection::Center(16)).unwrap(), [
+			"  $1,234.56     ",
+			"      -5.00 €   ",
+		]);
+		assert_eq!(format_currency_column(&[], CurrencyOptions::default(), FormatDirection::Right(8)).unwrap(), Vec::<String>::new());
+		assert_eq!(format_currency_column(&[(Decimal::new(i128::MAX, 0), &usd)], CurrencyOptions::default(), FormatDirection::None), None);
+	}
+	#[test]
+	fn test_decimal_rescale_overflow() {
+		/* 10^(scale difference) overflows, so every digit is dropped, but rounding mode still applies */
+		assert_eq!(Decimal::new(1, 40).rescale(0, RoundingMode::Ceiling), Some(Decimal::new(1, 0)));
+		assert_eq!(Decimal::new(1, 40).rescale(0, RoundingMode::Floor), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(-1, 40).rescale(0, RoundingMode::Floor), Some(Decimal::new(-1, 0)));
+		assert_eq!(Decimal::new(-1, 40).rescale(0, RoundingMode::Ceiling), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(i128::MAX, 60).rescale(1, RoundingMode::Ceiling), Some(Decimal::new(1, 1)));
+		assert_eq!(Decimal::new(i128::MIN, 39).rescale(0, RoundingMode::Floor), Some(Decimal::new(-1, 0)));
+		assert_eq!(Decimal::new(i128::MIN, 39).rescale(0, RoundingMode::HalfUp), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(i128::MAX, 39).rescale(0, RoundingMode::HalfEven), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(i128::MAX, 39).rescale(0, RoundingMode::Truncate), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(0, 50).rescale(0, RoundingMode::Ceiling), Some(Decimal::new(0, 0)));
+		assert_eq!(Decimal::new(0, 50).rescale(0, RoundingMode::Floor), Some(Decimal::new(0, 0)));
+	}
+	#[test]
+	fn test_fraction_new_min() {
+		/* gcd of isize::MIN inputs is 2^63, which doesn't fit isize */
+		assert_eq!(Fraction::new(isize::MIN, isize::MIN.unsigned_abs()), Some(Fraction { numerator: -1, denominator: 1 }));
+		assert_eq!(Fraction::new(isize::MIN, 2), Some(Fraction { numerator: isize::MIN / 2, denominator: 1 }));
+		assert_eq!(Fraction::new(isize::MIN, 3), Some(Fraction { numerator: isize::MIN, denominator: 3 }));
+		assert_eq!(Fraction::new(isize::MIN, usize::MAX), Some(Fraction { numerator: isize::MIN, denominator: usize::MAX }));
+		assert_eq!(Fraction::new(isize::MIN, 1 << 62), Some(Fraction { numerator: -2, denominator: 1 }));
+		assert_eq!(Fraction::new(isize::MAX, isize::MIN.unsigned_abs()), Some(Fraction { numerator: isize::MAX, denominator: isize::MIN.unsigned_abs() }));
+		assert_eq!(Fraction::new(0, isize::MIN.unsigned_abs()), Some(Fraction { numerator: 0, denominator: 1 }));
+		/* isize::MIN numerator is formatted and parsed back */
+		let minimum = Fraction::new(isize::MIN, 3).unwrap();
+		let none = FormatOptions::new(FormatDirection::None, None);
+		assert_eq!(format_fraction(minimum, FractionStyle::Ascii, none.clone()), "-9223372036854775808/3");
+		assert_eq!(format_fraction(minimum, FractionStyle::Mixed, none.clone()), "-3074457345618258602 2/3");
+		assert_eq!(format_fraction(minimum, FractionStyle::Unicode, none.clone()), "-3074457345618258602 ⅔");
+		assert_eq!(format_fraction(Fraction::new(isize::MIN, 1).unwrap(), FractionStyle::Ascii, none), "-9223372036854775808");
+		assert_eq!(parse_fraction("-9223372036854775808/3"), Ok(minimum));
+		assert_eq!(parse_fraction("-3074457345618258602 2/3"), Ok(minimum));
+		assert_eq!(parse_fraction("-9223372036854775808"), Ok(Fraction::new(isize::MIN, 1).unwrap()));
+		assert_eq!(parse_fraction("-9223372036854775809/3"), Err(ParseFractionKind::Overflow));
+		assert_eq!(parse_fraction("9223372036854775808/3"), Err(ParseFractionKind::Overflow));
+	}
+	#[test]
+	fn test_c_format_ordinal_locale() {
+		let numbers = || vec![
+			CFormatArgument::Int32(1),
+			CFormatArgument::Int32(11),
+			CFormatArgument::Int32(101),
+			CFormatArgument::UInt64(112),
+		];
+		assert_eq!(c_format("%~d|%~d|%~d|%~llu".to_string(), numbers()), "1st|11th|101st|112th");
+		assert_eq!(c_format_localized("%~d|%~d|%~d|%~llu".to_string(), numbers(), Locale::English), "1st|11th|101st|112th");
+		/* only one itself is `1er` in French */
+		assert_eq!(c_format_localized("%~d|%~d|%~d|%~llu".to_string(), numbers(), Locale::French), "1er|11e|101e|112e");
+		assert_eq!(c_format_localized("%~d|%~d|%~d|%~llu".to_string(), numbers(), Locale::German), "1.|11.|101.|112.");
+		assert_eq!(c_format_localized("%-~6d|%~d|%~d|%~llu".to_string(), numbers(), Locale::Spanish), "1.º   |11.º|101.º|112.º");
+		assert_eq!(c_format_localized("%~w128u".to_string(), vec![CFormatArgument::UInt128(u128::MAX - 254)], Locale::English), "340282366920938463463374607431768211201st");
+		assert_eq!(c_format_localized("%~w128u".to_string(), vec![CFormatArgument::UInt128(u128::MAX - 254)], Locale::French), "340282366920938463463374607431768211201e");
+		/* ordinal suffix is only appended to decimal conversions */
+		assert_eq!(c_format_localized("%~x".to_string(), vec![CFormatArgument::UInt32(1)], Locale::French), "1");
+		let mut format = CFormat::new("%~d place").unwrap();
+		assert_eq!(format.get_locale(), Locale::English);
+		assert_eq!(format.format(&[CFormatArgument::Int32(2)]), "2nd place");
+		assert_eq!(format.set_locale(Locale::French).format(&[CFormatArgument::Int32(2)]), "2e place");
+		assert_eq!(format.set_locale(Locale::French).format(&[CFormatArgument::Int32(1)]), "1er place");
+	}
 }
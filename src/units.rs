@@ -0,0 +1,681 @@
+//! Units of measurement formatting and parsing.
+//! 
+//! This module contains converters for human-readable byte sizes, durations and SI (metric) prefixes.
+
+use std::time::Duration;
+
+use crate::converters::Base;
+use crate::converters::Case;
+use crate::converters::atof;
+use crate::converters::atoi_signed;
+use crate::converters::atoi_unsigned;
+use crate::converters::etoa;
+use crate::converters::ftoa;
+use crate::converters::itoa_signed;
+use crate::converters::itoa_unsigned;
+use crate::format::FormatDirection;
+use crate::format::FormatOptions;
+use crate::format::format_text;
+
+/// Byte size unit system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ByteUnits {
+	/// SI units, powers of 1000 (`kB`, `MB`, `GB`, ...).
+	Decimal,
+	
+	/// IEC units, powers of 1024 (`KiB`, `MiB`, `GiB`, ...).
+	Binary,
+}
+
+impl ByteUnits {
+	/// Returns ratio between adjacent units.
+	pub fn base(&self) -> u64 {
+		match self {
+			ByteUnits::Decimal => 1000,
+			ByteUnits::Binary => 1024,
+		}
+	}
+	
+	/// Returns unit symbols, starting from bytes.
+	pub fn symbols(&self) -> [&'static str; 7] {
+		match self {
+			ByteUnits::Decimal => ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+			ByteUnits::Binary => ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+		}
+	}
+}
+
+/// Formats byte count using the largest unit which keeps value at least 1.
+/// Values below 1 kB (1 KiB) are written as whole bytes, others with `precision` digits after decimal point.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::{FormatDirection, FormatOptions};
+/// use rustrings::units::{ByteUnits, format_bytes};
+/// 
+/// let none = FormatOptions::new(FormatDirection::None, None);
+/// assert_eq!(format_bytes(512, ByteUnits::Decimal, 1, none.clone()), "512 B");
+/// assert_eq!(format_bytes(1500, ByteUnits::Decimal, 1, none.clone()), "1.5 kB");
+/// assert_eq!(format_bytes(1610612736, ByteUnits::Binary, 2, none.clone()), "1.50 GiB");
+/// assert_eq!(format_bytes(999_999, ByteUnits::Decimal, 1, none.clone()), "1.0 MB");
+/// assert_eq!(format_bytes(2048, ByteUnits::Binary, 0,
+///     FormatOptions::new(FormatDirection::Right(8), None)), "   2 KiB");
+/// ```
+pub fn format_bytes(
+	bytes: u64,
+	units: ByteUnits,
+	precision: usize,
+	options: FormatOptions,
+) -> String {
+	let base = units.base();
+	let symbols = units.symbols();
+	if bytes < base {
+		return format_text(&format!("{} {}", bytes, symbols[0]), options);
+	}
+	let mut exponent = 0;
+	let mut divisor = 1u64;
+	while exponent + 1 < symbols.len() && bytes / divisor >= base {
+		divisor *= base;
+		exponent += 1;
+	}
+	let mut value = ftoa(bytes as f64 / divisor as f64, precision);
+	/* rounding may carry into the next unit, e.g. 999.96 kB */
+	if exponent + 1 < symbols.len() && value.parse::<f64>().unwrap_or(0.0) >= base as f64 {
+		divisor *= base;
+		exponent += 1;
+		value = ftoa(bytes as f64 / divisor as f64, precision);
+	}
+	format_text(&format!("{} {}", value, symbols[exponent]), options)
+}
+
+/// Returned by `parse_bytes` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseBytesKind {
+	/// Empty string.
+	Empty,
+	
+	/// Number part is missing or malformed.
+	InvalidNumber,
+	
+	/// Unit is not recognized. Contains the unit as written.
+	InvalidUnit(String),
+	
+	/// Value does not fit in `u64`.
+	Overflow,
+}
+
+/// Parses unsigned decimal number with optional fraction into `(mantissa, scale)`, so value is `mantissa / 10^scale`.
+fn parse_fixed(o: &str) -> Result<(u128, u32), ParseBytesKind> {
+	let mut mantissa = 0u128;
+	let mut scale = 0u32;
+	let mut digits = false;
+	let mut point = false;
+	for char in o.chars() {
+		if char == '.' && !point {
+			point = true;
+			continue;
+		}
+		let digit = char
+			.to_digit(10)
+			.ok_or(ParseBytesKind::InvalidNumber)?;
+		digits = true;
+		mantissa = mantissa
+			.checked_mul(10)
+			.and_then(|e| e.checked_add(digit as u128))
+			.ok_or(ParseBytesKind::Overflow)?;
+		if point {
+			scale += 1;
+		}
+	}
+	if !digits {
+		return Err(ParseBytesKind::InvalidNumber);
+	}
+	Ok((mantissa, scale))
+}
+
+/// Returns multiplier of byte unit. Unit is case-insensitive; prefix without `B` is accepted.
+fn byte_unit_multiplier(unit: &str) -> Option<u128> {
+	let unit = unit.to_ascii_lowercase();
+	let unit = unit
+		.strip_suffix('b')
+		.unwrap_or(&unit);
+	let (prefix, binary) = match unit.strip_suffix('i') {
+		Some(prefix) if !prefix.is_empty() => (prefix, true),
+		_ => (unit, false),
+	};
+	let exponent = match prefix {
+		"" => 0,
+		"k" => 1,
+		"m" => 2,
+		"g" => 3,
+		"t" => 4,
+		"p" => 5,
+		"e" => 6,
+		_ => return None,
+	};
+	let base: u128 = if binary {
+		1024
+	} else {
+		1000
+	};
+	Some(base.pow(exponent))
+}
+
+/// Parses human-readable byte size, returning `Ok(u64)` if successfully parsed, otherwise returns `Err(ParseBytesKind)` on error.
+/// Accepts SI (`kB`, `MB`, ...) and IEC (`KiB`, `MiB`, ...) units in any case, with optional space and fractional value.
+/// Result is rounded to the nearest byte.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::units::{ParseBytesKind, parse_bytes};
+/// 
+/// assert_eq!(parse_bytes("1.5GiB"), Ok(1610612736));
+/// assert_eq!(parse_bytes("1.5 gb"), Ok(1500000000));
+/// assert_eq!(parse_bytes("10K"), Ok(10000));
+/// assert_eq!(parse_bytes("512"), Ok(512));
+/// assert_eq!(parse_bytes(""), Err(ParseBytesKind::Empty));
+/// assert_eq!(parse_bytes("GiB"), Err(ParseBytesKind::InvalidNumber));
+/// assert_eq!(parse_bytes("1.5 GiG"), Err(ParseBytesKind::InvalidUnit("GiG".to_string())));
+/// assert_eq!(parse_bytes("20 EiB"), Err(ParseBytesKind::Overflow));
+/// ```
+pub fn parse_bytes(o: &str) -> Result<u64, ParseBytesKind> {
+	let o = o.trim();
+	if o.is_empty() {
+		return Err(ParseBytesKind::Empty);
+	}
+	let split = o
+		.find(|e: char| !(e.is_ascii_digit() || e == '.'))
+		.unwrap_or(o.len());
+	let (number, unit) = o.split_at(split);
+	let unit = unit.trim_start();
+	let (mantissa, scale) = parse_fixed(number)?;
+	let multiplier = byte_unit_multiplier(unit)
+		.ok_or_else(|| ParseBytesKind::InvalidUnit(unit.to_string()))?;
+	let product = mantissa
+		.checked_mul(multiplier)
+		.ok_or(ParseBytesKind::Overflow)?;
+	let divisor = 10u128
+		.checked_pow(scale)
+		.ok_or(ParseBytesKind::Overflow)?;
+	let result = product
+		.checked_add(divisor / 2)
+		.ok_or(ParseBytesKind::Overflow)? / divisor;
+	u64::try_from(result).map_err(|_| ParseBytesKind::Overflow)
+}
+
+/// Duration formatting style.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DurationStyle {
+	/// Unit symbols without spaces, e.g. `1h30m5s`.
+	Compact,
+	
+	/// Unit names separated by commas, e.g. `1 hour, 30 minutes, 5 seconds`.
+	Verbose,
+	
+	/// ISO 8601 duration, e.g. `PT1H30M5S`.
+	Iso8601,
+}
+
+/// Duration units as `(nanoseconds, symbol, singular, plural)`, starting from the largest one.
+const DURATION_UNITS: [(u128, &str, &str, &str); 7] = [
+	(86_400_000_000_000, "d", "day", "days"),
+	(3_600_000_000_000, "h", "hour", "hours"),
+	(60_000_000_000, "m", "minute", "minutes"),
+	(1_000_000_000, "s", "second", "seconds"),
+	(1_000_000, "ms", "millisecond", "milliseconds"),
+	(1_000, "µs", "microsecond", "microseconds"),
+	(1, "ns", "nanosecond", "nanoseconds"),
+];
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Formats duration. Zero components are omitted.
+/// Compact and verbose styles write sub-second part in milli-, micro- and nanoseconds,
+/// ISO 8601 style writes it as fraction of second.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::time::Duration;
+/// use rustrings::units::{DurationStyle, format_duration};
+/// 
+/// assert_eq!(format_duration(Duration::from_secs(5400), DurationStyle::Compact), "1h30m");
+/// assert_eq!(format_duration(Duration::from_secs(5405), DurationStyle::Verbose), "1 hour, 30 minutes, 5 seconds");
+/// assert_eq!(format_duration(Duration::from_secs(5400), DurationStyle::Iso8601), "PT1H30M");
+/// assert_eq!(format_duration(Duration::from_millis(1500), DurationStyle::Compact), "1s500ms");
+/// assert_eq!(format_duration(Duration::from_millis(1500), DurationStyle::Iso8601), "PT1.5S");
+/// assert_eq!(format_duration(Duration::ZERO, DurationStyle::Verbose), "0 seconds");
+/// ```
+pub fn format_duration(
+	duration: Duration,
+	style: DurationStyle,
+) -> String {
+	if style == DurationStyle::Iso8601 {
+		return format_iso_duration(duration);
+	}
+	let mut nanos = duration.as_nanos();
+	let mut parts = Vec::new();
+	for (unit, symbol, singular, plural) in DURATION_UNITS {
+		let count = nanos / unit;
+		nanos %= unit;
+		if count == 0 {
+			continue;
+		}
+		let count_text = itoa_unsigned(count as usize, Base::new(10), Case::Lower);
+		parts.push(if style == DurationStyle::Compact {
+			format!("{}{}", count_text, symbol)
+		} else if count == 1 {
+			format!("{} {}", count_text, singular)
+		} else {
+			format!("{} {}", count_text, plural)
+		});
+	}
+	match style {
+		DurationStyle::Compact if parts.is_empty() => "0s".to_string(),
+		DurationStyle::Compact => parts.concat(),
+		_ if parts.is_empty() => "0 seconds".to_string(),
+		_ => parts.join(", "),
+	}
+}
+
+fn format_iso_duration(duration: Duration) -> String {
+	let seconds = duration.as_secs() as usize;
+	let nanos = duration.subsec_nanos() as usize;
+	let (days, hours, minutes, seconds) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+	let mut result = "P".to_string();
+	if days != 0 {
+		result.push_str(&itoa_unsigned(days, Base::new(10), Case::Upper));
+		result.push('D');
+	}
+	if days != 0 && hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 {
+		return result;
+	}
+	result.push('T');
+	if hours != 0 {
+		result.push_str(&itoa_unsigned(hours, Base::new(10), Case::Upper));
+		result.push('H');
+	}
+	if minutes != 0 {
+		result.push_str(&itoa_unsigned(minutes, Base::new(10), Case::Upper));
+		result.push('M');
+	}
+	if seconds != 0 || nanos != 0 || (hours == 0 && minutes == 0) {
+		result.push_str(&itoa_unsigned(seconds, Base::new(10), Case::Upper));
+		if nanos != 0 {
+			let fraction = format_text(&itoa_unsigned(nanos, Base::new(10), Case::Upper),
+				FormatOptions::new(FormatDirection::Right(9), Some('0')));
+			result.push('.');
+			result.push_str(fraction.trim_end_matches('0'));
+		}
+		result.push('S');
+	}
+	result
+}
+
+/// Returned by `parse_duration` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseDurationKind {
+	/// Empty string.
+	Empty,
+	
+	/// Number part is missing or malformed.
+	InvalidNumber,
+	
+	/// Number is not followed by unit.
+	MissingUnit,
+	
+	/// Unit is not recognized or is out of order. Contains the unit as written.
+	InvalidUnit(String),
+	
+	/// Value does not fit in `Duration`.
+	Overflow,
+}
+
+/// Returns length of duration unit in nanoseconds. Accepts symbols, singular and plural names.
+fn duration_unit_nanos(unit: &str) -> Option<u128> {
+	let unit = unit.to_lowercase();
+	let nanos = match unit.as_str() {
+		"w" | "wk" | "week" | "weeks" => 7 * DURATION_UNITS[0].0,
+		"us" | "μs" => DURATION_UNITS[5].0,
+		"sec" | "secs" => DURATION_UNITS[3].0,
+		"min" | "mins" => DURATION_UNITS[2].0,
+		"hr" | "hrs" => DURATION_UNITS[1].0,
+		_ => DURATION_UNITS
+			.iter()
+			.find(|(_, symbol, singular, plural)| unit == *symbol || unit == *singular || unit == *plural)?
+			.0,
+	};
+	Some(nanos)
+}
+
+/// Converts number of `unit` into nanoseconds. Integers are parsed exactly, fractions via `atof`.
+fn component_nanos(
+	number: &str,
+	unit: u128,
+) -> Result<u128, ParseDurationKind> {
+	if number.is_empty() || number.matches('.').count() > 1 {
+		return Err(ParseDurationKind::InvalidNumber);
+	}
+	if number.contains('.') {
+		let value = atof(number).map_err(|_| ParseDurationKind::InvalidNumber)? * unit as f64;
+		if value >= u128::MAX as f64 {
+			return Err(ParseDurationKind::Overflow);
+		}
+		return Ok(value.round() as u128);
+	}
+	let value = atoi_unsigned(number, Base::new(10), false).map_err(|_| ParseDurationKind::Overflow)?;
+	(value as u128)
+		.checked_mul(unit)
+		.ok_or(ParseDurationKind::Overflow)
+}
+
+fn nanos_to_duration(nanos: u128) -> Result<Duration, ParseDurationKind> {
+	let seconds = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| ParseDurationKind::Overflow)?;
+	Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+}
+
+fn parse_iso_duration(o: &str) -> Result<Duration, ParseDurationKind> {
+	let mut rest = o;
+	let mut time = false;
+	let mut components = 0;
+	let mut previous = u128::MAX;
+	let mut nanos = 0u128;
+	while !rest.is_empty() {
+		if let Some(stripped) = rest.strip_prefix('T') {
+			if time {
+				return Err(ParseDurationKind::InvalidUnit("T".to_string()));
+			}
+			time = true;
+			rest = stripped;
+			continue;
+		}
+		let split = rest
+			.find(|e: char| !(e.is_ascii_digit() || e == '.' || e == ','))
+			.unwrap_or(rest.len());
+		let (number, tail) = rest.split_at(split);
+		let mut tail = tail.chars();
+		let designator = tail
+			.next()
+			.ok_or(ParseDurationKind::MissingUnit)?;
+		let unit = match (time, designator) {
+			(false, 'W') => 7 * DURATION_UNITS[0].0,
+			(false, 'D') => DURATION_UNITS[0].0,
+			(true, 'H') => DURATION_UNITS[1].0,
+			(true, 'M') => DURATION_UNITS[2].0,
+			(true, 'S') => DURATION_UNITS[3].0,
+			_ => return Err(ParseDurationKind::InvalidUnit(designator.to_string())),
+		};
+		if unit >= previous {
+			return Err(ParseDurationKind::InvalidUnit(designator.to_string()));
+		}
+		previous = unit;
+		nanos = nanos
+			.checked_add(component_nanos(&number.replace(',', "."), unit)?)
+			.ok_or(ParseDurationKind::Overflow)?;
+		components += 1;
+		rest = tail.as_str();
+	}
+	if components == 0 {
+		return Err(ParseDurationKind::InvalidNumber);
+	}
+	nanos_to_duration(nanos)
+}
+
+/// Parses duration, returning `Ok(Duration)` if successfully parsed, otherwise returns `Err(ParseDurationKind)` on error.
+/// Accepts compact (`1h30m`, `1h 30m 5s`), verbose (`1 hour, 30 minutes and 5 seconds`) and ISO 8601 (`PT1H30M`) forms.
+/// Components may have fractions (`1.5h`, `PT0.5S`).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::time::Duration;
+/// use rustrings::units::{ParseDurationKind, parse_duration};
+/// 
+/// assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration("1h 30m 5s"), Ok(Duration::from_secs(5405)));
+/// assert_eq!(parse_duration("1 hour, 30 minutes"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration("PT1H30M"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+/// assert_eq!(parse_duration("30"), Err(ParseDurationKind::MissingUnit));
+/// assert_eq!(parse_duration("3 fortnights"), Err(ParseDurationKind::InvalidUnit("fortnights".to_string())));
+/// ```
+pub fn parse_duration(o: &str) -> Result<Duration, ParseDurationKind> {
+	let o = o.trim();
+	if o.is_empty() {
+		return Err(ParseDurationKind::Empty);
+	}
+	if let Some(iso) = o.strip_prefix('P') {
+		return parse_iso_duration(iso);
+	}
+	let mut rest = o;
+	let mut nanos = 0u128;
+	loop {
+		rest = rest.trim_start_matches(|e: char| e.is_whitespace() || e == ',');
+		if let Some(stripped) = rest.strip_prefix("and").filter(|e| e.starts_with(char::is_whitespace)) {
+			rest = stripped;
+			continue;
+		}
+		if rest.is_empty() {
+			break;
+		}
+		let split = rest
+			.find(|e: char| !(e.is_ascii_digit() || e == '.'))
+			.unwrap_or(rest.len());
+		let (number, tail) = rest.split_at(split);
+		let tail = tail.trim_start();
+		let split = tail
+			.find(|e: char| !e.is_alphabetic())
+			.unwrap_or(tail.len());
+		let (unit, tail) = tail.split_at(split);
+		if number.is_empty() {
+			return Err(ParseDurationKind::InvalidNumber);
+		}
+		if unit.is_empty() {
+			return Err(ParseDurationKind::MissingUnit);
+		}
+		let unit_nanos = duration_unit_nanos(unit)
+			.ok_or_else(|| ParseDurationKind::InvalidUnit(unit.to_string()))?;
+		nanos = nanos
+			.checked_add(component_nanos(number, unit_nanos)?)
+			.ok_or(ParseDurationKind::Overflow)?;
+		rest = tail;
+	}
+	nanos_to_duration(nanos)
+}
+
+/// SI number notation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SiNotation {
+	/// Metric prefix, e.g. `47 µV`.
+	Prefix,
+	
+	/// Engineering notation with exponent which is multiple of 3, e.g. `47e-6 V`.
+	Engineering,
+}
+
+/// Metric prefixes as `(exponent, symbol)`.
+const SI_PREFIXES: [(isize, &str); 21] = [
+	(-30, "q"), (-27, "r"), (-24, "y"), (-21, "z"), (-18, "a"), (-15, "f"), (-12, "p"),
+	(-9, "n"), (-6, "µ"), (-3, "m"), (0, ""), (3, "k"), (6, "M"), (9, "G"),
+	(12, "T"), (15, "P"), (18, "E"), (21, "Z"), (24, "Y"), (27, "R"), (30, "Q"),
+];
+
+/// Formats value with metric prefix or in engineering notation, rounded to `significant` significant figures.
+/// If value is out of range of metric prefixes, engineering notation is used.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::format::{FormatDirection, FormatOptions};
+/// use rustrings::units::{SiNotation, format_si};
+/// 
+/// let none = FormatOptions::new(FormatDirection::None, None);
+/// assert_eq!(format_si(0.000047, "", 2, SiNotation::Prefix, none.clone()), "47 µ");
+/// assert_eq!(format_si(0.000047, "", 2, SiNotation::Engineering, none.clone()), "47e-6");
+/// assert_eq!(format_si(4700.0, "Ω", 3, SiNotation::Prefix, none.clone()), "4.70 kΩ");
+/// assert_eq!(format_si(2.5, "V", 2, SiNotation::Prefix, none.clone()), "2.5 V");
+/// assert_eq!(format_si(-1234567.0, "Hz", 3, SiNotation::Prefix,
+///     FormatOptions::new(FormatDirection::Right(10), None)), " -1.23 MHz");
+/// ```
+pub fn format_si(
+	value: f64,
+	unit: &str,
+	significant: usize,
+	notation: SiNotation,
+	options: FormatOptions,
+) -> String {
+	let mut text = if value.is_finite() {
+		let scientific = etoa(value, significant.max(1) - 1, Case::Lower);
+		let (mantissa, exponent) = scientific
+			.split_once('e')
+			.unwrap();
+		let exponent = atoi_signed(exponent.trim_start_matches('+'), Base::new(10), false).unwrap_or(0);
+		let engineering = exponent.div_euclid(3) * 3;
+		let integer = (exponent - engineering) as usize + 1;
+		let mut digits = mantissa
+			.chars()
+			.filter(char::is_ascii_digit)
+			.collect::<String>();
+		while digits.len() < integer {
+			digits.push('0');
+		}
+		let mut text = if mantissa.starts_with('-') {
+			"-".to_string()
+		} else {
+			String::new()
+		};
+		text.push_str(&digits[..integer]);
+		if digits.len() > integer {
+			text.push('.');
+			text.push_str(&digits[integer..]);
+		}
+		let prefix = SI_PREFIXES
+			.iter()
+			.find(|(e, _)| *e == engineering);
+		match (notation, prefix) {
+			(SiNotation::Prefix, Some((_, symbol))) => {
+				if !symbol.is_empty() || !unit.is_empty() {
+					text.push(' ');
+					text.push_str(symbol);
+				}
+			},
+			_ => {
+				text.push('e');
+				text.push_str(&itoa_signed(engineering, Base::new(10), Case::Lower));
+				if !unit.is_empty() {
+					text.push(' ');
+				}
+			},
+		}
+		text
+	} else {
+		let mut text = ftoa(value, 0);
+		if !unit.is_empty() {
+			text.push(' ');
+		}
+		text
+	};
+	text.push_str(unit);
+	format_text(&text, options)
+}
+
+/// Returned by `parse_si` if parsing of string failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseSiKind {
+	/// Empty string.
+	Empty,
+	
+	/// Number part is missing or malformed.
+	InvalidNumber,
+	
+	/// Metric prefix is not recognized. Contains the rest of string after number.
+	InvalidPrefix(String),
+}
+
+/// Parses value with optional metric prefix and optional `unit`, returning `Ok(f64)` if successfully parsed, otherwise returns `Err(ParseSiKind)` on error.
+/// Accepts `2.2M`, `47 µ` (or `47u`), `1.5e3` and RKM code, where prefix replaces decimal point (`4k7`, `4R7`).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::units::{ParseSiKind, parse_si};
+/// 
+/// assert_eq!(parse_si("4k7", ""), Ok(4700.0));
+/// assert_eq!(parse_si("2.2M", ""), Ok(2200000.0));
+/// assert_eq!(parse_si("47 µ", ""), Ok(0.000047));
+/// assert_eq!(parse_si("4.7 kΩ", "Ω"), Ok(4700.0));
+/// assert_eq!(parse_si("1R5", ""), Ok(1.5));
+/// assert_eq!(parse_si("3 x", ""), Err(ParseSiKind::InvalidPrefix("x".to_string())));
+/// ```
+pub fn parse_si(
+	o: &str,
+	unit: &str,
+) -> Result<f64, ParseSiKind> {
+	let o = o.trim();
+	let o = o
+		.strip_suffix(unit)
+		.unwrap_or(o)
+		.trim_end();
+	if o.is_empty() {
+		return Err(ParseSiKind::Empty);
+	}
+	let bytes = o.as_bytes();
+	let mut position = 0;
+	if bytes[0] == b'-' || bytes[0] == b'+' {
+		position += 1;
+	}
+	while position < bytes.len() && (bytes[position].is_ascii_digit() || bytes[position] == b'.') {
+		position += 1;
+	}
+	let mut exponent = false;
+	if position < bytes.len() && (bytes[position] == b'e' || bytes[position] == b'E') {
+		let mut end = position + 1;
+		if end < bytes.len() && (bytes[end] == b'-' || bytes[end] == b'+') {
+			end += 1;
+		}
+		if end < bytes.len() && bytes[end].is_ascii_digit() {
+			exponent = true;
+			position = end;
+			while position < bytes.len() && bytes[position].is_ascii_digit() {
+				position += 1;
+			}
+		}
+	}
+	let (number, rest) = o.split_at(position);
+	if number.matches('.').count() > 1 || !number.bytes().any(|e| e.is_ascii_digit()) {
+		return Err(ParseSiKind::InvalidNumber);
+	}
+	let rest = rest.trim_start();
+	let mut chars = rest.chars();
+	let magnitude = match chars.next() {
+		None => 0,
+		Some('R' | 'r') => 0,
+		Some('u' | 'μ') => -6,
+		Some('K') => 3,
+		Some(symbol) => SI_PREFIXES
+			.iter()
+			.find(|(_, e)| e.starts_with(symbol) && !e.is_empty())
+			.ok_or_else(|| ParseSiKind::InvalidPrefix(rest.to_string()))?
+			.0,
+	};
+	let fraction = chars.as_str();
+	if (exponent && !rest.is_empty()) || (rest.starts_with(['R', 'r']) && fraction.is_empty()) {
+		return Err(ParseSiKind::InvalidPrefix(rest.to_string()));
+	}
+	let mut text = number.to_string();
+	if !fraction.is_empty() {
+		/* RKM code: prefix stands for decimal point */
+		if number.contains('.') || !fraction.bytes().all(|e| e.is_ascii_digit()) {
+			return Err(ParseSiKind::InvalidPrefix(rest.to_string()));
+		}
+		text.push('.');
+		text.push_str(fraction);
+	}
+	if !exponent {
+		text.push('e');
+		text.push_str(&itoa_signed(magnitude, Base::new(10), Case::Lower));
+	}
+	atof(&text).map_err(|_| ParseSiKind::InvalidNumber)
+}
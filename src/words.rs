@@ -0,0 +1,471 @@
+//! Number to words operations.
+//! 
+//! This module contains converters to spell out numbers as cardinals ("twenty-one") and ordinals ("twenty-first").
+//! Language rules are pluggable via `Language` trait.
+
+/// Language rules for spelling out numbers.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::words::{English, Language, cardinal};
+/// 
+/// struct Pirate;
+/// 
+/// impl Language for Pirate {
+///     fn cardinal(&self, number: u128) -> String {
+///         format!("{}, arr", English.cardinal(number))
+///     }
+/// 
+///     fn ordinal(&self, number: u128) -> String {
+///         format!("{}, arr", English.ordinal(number))
+///     }
+/// 
+///     fn minus(&self) -> &'static str {
+///         "negative"
+///     }
+/// }
+/// 
+/// assert_eq!(cardinal(-7, &Pirate), "negative seven, arr");
+/// ```
+pub trait Language {
+	/// Spells out non-negative number as cardinal.
+	fn cardinal(&self, number: u128) -> String;
+
+	/// Spells out non-negative number as ordinal.
+	fn ordinal(&self, number: u128) -> String;
+
+	/// Returns word which is prepended to negative numbers.
+	fn minus(&self) -> &'static str;
+}
+
+/// Spells out number as cardinal.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::words::{English, French, German, cardinal};
+/// 
+/// assert_eq!(cardinal(1234, &English), "one thousand two hundred thirty-four");
+/// assert_eq!(cardinal(-21, &English), "minus twenty-one");
+/// assert_eq!(cardinal(80, &French), "quatre-vingts");
+/// assert_eq!(cardinal(21, &German), "einundzwanzig");
+/// ```
+pub fn cardinal(
+	number: i128,
+	language: &dyn Language,
+) -> String {
+	let words = language.cardinal(number.unsigned_abs());
+	if number < 0 {
+		format!("{} {}", language.minus(), words)
+	} else {
+		words
+	}
+}
+
+/// Spells out number as ordinal.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rustrings::words::{English, French, German, ordinal};
+/// 
+/// assert_eq!(ordinal(21, &English), "twenty-first");
+/// assert_eq!(ordinal(1, &French), "premier");
+/// assert_eq!(ordinal(3, &German), "dritte");
+/// ```
+pub fn ordinal(
+	number: i128,
+	language: &dyn Language,
+) -> String {
+	let words = language.ordinal(number.unsigned_abs());
+	if number < 0 {
+		format!("{} {}", language.minus(), words)
+	} else {
+		words
+	}
+}
+
+/// Splits number into groups of three digits, starting from the lowest one.
+fn thousands_groups(number: u128) -> Vec<u128> {
+	let mut number = number;
+	let mut result = Vec::new();
+	loop {
+		result.push(number % 1000);
+		number /= 1000;
+		if number == 0 {
+			break;
+		}
+	}
+	result
+}
+
+/// Replaces last word (separated by space or hyphen) of `words` using `replace`.
+fn replace_last_word(
+	words: &str,
+	replace: impl Fn(&str) -> String,
+) -> String {
+	let position = words
+		.rfind([' ', '-'])
+		.map(|e| e + 1)
+		.unwrap_or(0);
+	let mut result = words[..position].to_string();
+	result.push_str(&replace(&words[position..]));
+	result
+}
+
+const ENGLISH_UNITS: [&str; 20] = [
+	"zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+	"ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const ENGLISH_TENS: [&str; 10] = [
+	"", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const ENGLISH_SCALES: [&str; 13] = [
+	"", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+	"sextillion", "septillion", "octillion", "nonillion", "decillion", "undecillion",
+];
+
+/// English (short scale, American style without "and").
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl English {
+	fn below_thousand(number: u128) -> String {
+		let mut result = String::new();
+		let hundreds = number / 100;
+		let rest = (number % 100) as usize;
+		if hundreds != 0 {
+			result.push_str(ENGLISH_UNITS[hundreds as usize]);
+			result.push_str(" hundred");
+			if rest != 0 {
+				result.push(' ');
+			}
+		}
+		if rest >= 20 {
+			result.push_str(ENGLISH_TENS[rest / 10]);
+			if !rest.is_multiple_of(10) {
+				result.push('-');
+				result.push_str(ENGLISH_UNITS[rest % 10]);
+			}
+		} else if rest != 0 || hundreds == 0 {
+			result.push_str(ENGLISH_UNITS[rest]);
+		}
+		result
+	}
+}
+
+impl Language for English {
+	fn cardinal(&self, number: u128) -> String {
+		if number == 0 {
+			return ENGLISH_UNITS[0].to_string();
+		}
+		let mut result = Vec::new();
+		for (scale, group) in thousands_groups(number).iter().enumerate().rev() {
+			if *group == 0 {
+				continue;
+			}
+			result.push(English::below_thousand(*group));
+			if scale != 0 {
+				result.push(ENGLISH_SCALES[scale].to_string());
+			}
+		}
+		result.join(" ")
+	}
+
+	fn ordinal(&self, number: u128) -> String {
+		replace_last_word(&self.cardinal(number), |word| match word {
+			"one" => "first".to_string(),
+			"two" => "second".to_string(),
+			"three" => "third".to_string(),
+			"five" => "fifth".to_string(),
+			"eight" => "eighth".to_string(),
+			"nine" => "ninth".to_string(),
+			"twelve" => "twelfth".to_string(),
+			_ => if let Some(stem) = word.strip_suffix('y') {
+				format!("{}ieth", stem)
+			} else {
+				format!("{}th", word)
+			},
+		})
+	}
+
+	fn minus(&self) -> &'static str {
+		"minus"
+	}
+}
+
+const FRENCH_UNITS: [&str; 20] = [
+	"zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+	"dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit", "dix-neuf",
+];
+
+const FRENCH_TENS: [&str; 7] = [
+	"", "", "vingt", "trente", "quarante", "cinquante", "soixante",
+];
+
+const FRENCH_SCALES: [&str; 13] = [
+	"", "mille", "million", "milliard", "billion", "billiard", "trillion",
+	"trilliard", "quadrillion", "quadrilliard", "quintillion", "quintilliard", "sextillion",
+];
+
+/// French (long scale, traditional spelling).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct French;
+
+impl French {
+	/// `plural` tells whether `vingt` and `cent` may take plural `s`,
+	/// which is the case at the end of number or before nouns like `million`, but not before `mille`.
+	fn below_hundred(
+		number: usize,
+		plural: bool,
+	) -> String {
+		match number {
+			0..=19 => FRENCH_UNITS[number].to_string(),
+			20..=59 => {
+				let tens = FRENCH_TENS[number / 10];
+				match number % 10 {
+					0 => tens.to_string(),
+					1 => format!("{} et un", tens),
+					units => format!("{}-{}", tens, FRENCH_UNITS[units]),
+				}
+			},
+			60..=79 => match number - 60 {
+				0 => "soixante".to_string(),
+				1 => "soixante et un".to_string(),
+				11 => "soixante et onze".to_string(),
+				rest => format!("soixante-{}", French::below_hundred(rest, plural)),
+			},
+			80 => if plural {
+				"quatre-vingts".to_string()
+			} else {
+				"quatre-vingt".to_string()
+			},
+			_ => format!("quatre-vingt-{}", French::below_hundred(number - 80, plural)),
+		}
+	}
+
+	fn below_thousand(
+		number: u128,
+		plural: bool,
+	) -> String {
+		let hundreds = (number / 100) as usize;
+		let rest = (number % 100) as usize;
+		let mut result = String::new();
+		match hundreds {
+			0 => {},
+			1 => result.push_str("cent"),
+			_ => {
+				result.push_str(FRENCH_UNITS[hundreds]);
+				result.push_str(" cent");
+				if rest == 0 && plural {
+					result.push('s');
+				}
+			},
+		}
+		if rest != 0 || hundreds == 0 {
+			if hundreds != 0 {
+				result.push(' ');
+			}
+			result.push_str(&French::below_hundred(rest, plural));
+		}
+		result
+	}
+}
+
+impl Language for French {
+	fn cardinal(&self, number: u128) -> String {
+		if number == 0 {
+			return FRENCH_UNITS[0].to_string();
+		}
+		let mut result = Vec::new();
+		for (scale, group) in thousands_groups(number).iter().enumerate().rev() {
+			match (scale, *group) {
+				(_, 0) => {},
+				(0, group) => result.push(French::below_thousand(group, true)),
+				(1, 1) => result.push(FRENCH_SCALES[1].to_string()),
+				(1, group) => {
+					result.push(French::below_thousand(group, false));
+					result.push(FRENCH_SCALES[1].to_string());
+				},
+				(scale, group) => {
+					result.push(French::below_thousand(group, true));
+					result.push(if group == 1 {
+						FRENCH_SCALES[scale].to_string()
+					} else {
+						format!("{}s", FRENCH_SCALES[scale])
+					});
+				},
+			}
+		}
+		result.join(" ")
+	}
+
+	fn ordinal(&self, number: u128) -> String {
+		if number == 1 {
+			return "premier".to_string();
+		}
+		replace_last_word(&self.cardinal(number), |word| {
+			let stem = match word {
+				"cinq" => "cinqu",
+				"neuf" => "neuv",
+				"cents" | "vingts" => &word[..word.len() - 1],
+				_ if word.ends_with("ions") || word.ends_with("iards") => &word[..word.len() - 1],
+				_ => word,
+			};
+			let stem = stem
+				.strip_suffix('e')
+				.unwrap_or(stem);
+			format!("{}ième", stem)
+		})
+	}
+
+	fn minus(&self) -> &'static str {
+		"moins"
+	}
+}
+
+const GERMAN_UNITS: [&str; 20] = [
+	"null", "ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+	"zehn", "elf", "zwölf", "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn", "achtzehn", "neunzehn",
+];
+
+const GERMAN_ORDINAL_UNITS: [&str; 20] = [
+	"nullte", "erste", "zweite", "dritte", "vierte", "fünfte", "sechste", "siebte", "achte", "neunte",
+	"zehnte", "elfte", "zwölfte", "dreizehnte", "vierzehnte", "fünfzehnte", "sechzehnte", "siebzehnte", "achtzehnte", "neunzehnte",
+];
+
+const GERMAN_TENS: [&str; 10] = [
+	"", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// Singular and plural forms of scale nouns, starting from `Million`.
+const GERMAN_SCALES: [(&str, &str); 11] = [
+	("Million", "Millionen"), ("Milliarde", "Milliarden"),
+	("Billion", "Billionen"), ("Billiarde", "Billiarden"),
+	("Trillion", "Trillionen"), ("Trilliarde", "Trilliarden"),
+	("Quadrillion", "Quadrillionen"), ("Quadrilliarde", "Quadrilliarden"),
+	("Quintillion", "Quintillionen"), ("Quintilliarde", "Quintilliarden"),
+	("Sextillion", "Sextillionen"),
+];
+
+/// German (long scale). Numbers below one million are written as single word.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct German;
+
+impl German {
+	/// `last` tells whether number ends the whole numeral, so trailing one is written as `eins`.
+	fn below_thousand(
+		number: u128,
+		last: bool,
+	) -> String {
+		let hundreds = (number / 100) as usize;
+		let rest = (number % 100) as usize;
+		let mut result = String::new();
+		if hundreds != 0 {
+			result.push_str(GERMAN_UNITS[hundreds]);
+			result.push_str("hundert");
+		}
+		match rest {
+			0 => {},
+			1 => result.push_str(if last {
+				"eins"
+			} else {
+				"ein"
+			}),
+			2..=19 => result.push_str(GERMAN_UNITS[rest]),
+			_ => {
+				if !rest.is_multiple_of(10) {
+					result.push_str(GERMAN_UNITS[rest % 10]);
+					result.push_str("und");
+				}
+				result.push_str(GERMAN_TENS[rest / 10]);
+			},
+		}
+		result
+	}
+
+	fn below_million(number: u128) -> String {
+		let thousands = number / 1000;
+		let rest = number % 1000;
+		let mut result = String::new();
+		if thousands != 0 {
+			result.push_str(&German::below_thousand(thousands, false));
+			result.push_str("tausend");
+		}
+		result.push_str(&German::below_thousand(rest, true));
+		result
+	}
+
+	/// Returns `(count, scale)` pairs for groups of million and above, starting from the highest one.
+	fn scale_groups(number: u128) -> Vec<(u128, usize)> {
+		thousands_groups(number / 1_000_000)
+			.iter()
+			.enumerate()
+			.rev()
+			.filter(|(_, group)| **group != 0)
+			.map(|(scale, group)| (*group, scale))
+			.collect()
+	}
+}
+
+impl Language for German {
+	fn cardinal(&self, number: u128) -> String {
+		if number == 0 {
+			return GERMAN_UNITS[0].to_string();
+		}
+		let mut result = Vec::new();
+		for (group, scale) in German::scale_groups(number) {
+			if group == 1 {
+				result.push("eine".to_string());
+				result.push(GERMAN_SCALES[scale].0.to_string());
+			} else {
+				result.push(German::below_thousand(group, false));
+				result.push(GERMAN_SCALES[scale].1.to_string());
+			}
+		}
+		if !number.is_multiple_of(1_000_000) {
+			result.push(German::below_million(number % 1_000_000));
+		}
+		result.join(" ")
+	}
+
+	fn ordinal(&self, number: u128) -> String {
+		if number == 0 {
+			return GERMAN_ORDINAL_UNITS[0].to_string();
+		}
+		if number.is_multiple_of(1_000_000) {
+			/* "zwei Millionen" becomes "zweimillionste" */
+			let groups = German::scale_groups(number);
+			let mut result = String::new();
+			for (index, (group, scale)) in groups.iter().enumerate() {
+				result.push_str(&German::below_thousand(*group, false));
+				let (singular, plural) = GERMAN_SCALES[*scale];
+				result.push_str(&if index + 1 == groups.len() || *group == 1 {
+					singular
+				} else {
+					plural
+				}.to_lowercase());
+			}
+			result.push_str("ste");
+			return result;
+		}
+		let rest = (number % 100) as usize;
+		let cardinal = self.cardinal(number);
+		if rest == 0 || rest >= 20 {
+			return format!("{}ste", cardinal);
+		}
+		let units = if rest == 1 {
+			"eins"
+		} else {
+			GERMAN_UNITS[rest]
+		};
+		format!("{}{}", &cardinal[..cardinal.len() - units.len()], GERMAN_ORDINAL_UNITS[rest])
+	}
+
+	fn minus(&self) -> &'static str {
+		"minus"
+	}
+}